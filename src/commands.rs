@@ -0,0 +1,55 @@
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::BuildChildren;
+use bevy_log::{debug, Level};
+use bevy_utils::tracing::enabled;
+use moonshine_kind::{prelude::*, Any};
+
+use crate::{Object, ObjectHierarchy, ObjectInstance, ObjectMut, ObjectRef, ObjectRefMut};
+
+/// Provides [`Commands`] which modify the hierarchy of an object.
+pub trait ObjectCommands<T: Kind = Any>: ObjectInstance<T> {
+    /// Queues a command which sets the parent of this object to `new_parent`.
+    ///
+    /// This removes this object from the children of its previous parent, if any.
+    /// The change is visible after the command is applied.
+    fn reparent(&self, commands: &mut Commands, new_parent: impl Into<Entity>) {
+        commands.entity(self.entity()).set_parent(new_parent.into());
+    }
+
+    /// Queues a command which detaches this object from its parent, making it a root object.
+    fn clear_parent(&self, commands: &mut Commands) {
+        commands.entity(self.entity()).remove_parent();
+    }
+
+    /// Queues a command which sets the [`Name`] of this object, replacing any existing name.
+    fn set_name(&self, commands: &mut Commands, name: impl Into<Name>) {
+        commands.entity(self.entity()).insert(name.into());
+    }
+
+    /// Queues commands which despawn this object and all of its descendants.
+    ///
+    /// The subtree is collected when this is called, and despawned from the deepest descendants up.
+    /// The [`path`](ObjectHierarchy::path) of this object is logged at debug level, and only computed if it is enabled.
+    fn despawn(&self, commands: &mut Commands)
+    where
+        Self: ObjectHierarchy<T>,
+    {
+        if enabled!(Level::DEBUG) {
+            debug!("despawning {}", self.path());
+        }
+        let entities = self.self_and_subtree_entities();
+        commands.entity(self.entity()).remove_parent();
+        for entity in entities.into_iter().rev() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+impl<T: Kind> ObjectCommands<T> for Object<'_, '_, '_, T> {}
+
+impl<T: Kind> ObjectCommands<T> for ObjectRef<'_, '_, '_, T> {}
+
+impl<T: Component> ObjectCommands<T> for ObjectMut<'_, '_, '_, T> {}
+
+impl<T: Kind> ObjectCommands<T> for ObjectRefMut<'_, '_, '_, T> {}
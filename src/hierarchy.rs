@@ -1,16 +1,53 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashSet, VecDeque},
+    fmt,
+    marker::PhantomData,
+};
+
 use bevy_ecs::prelude::*;
 use bevy_ecs::query::{QueryData, QueryFilter, QueryItem};
+use bevy_log::warn_once;
 use moonshine_kind::{prelude::*, Any};
+use smallvec::SmallVec;
+
+use crate::{
+    Object, ObjectInstance, ObjectMut, ObjectName, ObjectPath, ObjectPathSegment, ObjectRebind,
+    ObjectRef, ObjectRefMut, Objects,
+};
 
-use crate::{Object, ObjectInstance, ObjectName, ObjectRebind, ObjectRef, Objects};
+/// Number of objects in a path which may be collected without a heap allocation.
+const PATH_INLINE_DEPTH: usize = 8;
 
 pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
     fn parent(&self) -> Option<Self::Rebind<Any>>;
 
     fn root(&self) -> Self::Rebind<Any> {
-        self.ancestors()
-            .last()
-            .unwrap_or_else(|| self.rebind_any(self.entity()))
+        self.rebind_any(self.root_entity())
+    }
+
+    /// Returns the [`Entity`] of the root of this object's hierarchy.
+    ///
+    /// This is cheaper than [`root`](ObjectHierarchy::root) if only the entity is needed.
+    fn root_entity(&self) -> Entity;
+
+    /// Returns the ancestor of this object `n` levels above its parent.
+    ///
+    /// `nth_ancestor(0)` is the parent, `nth_ancestor(1)` is the grandparent, and so on.
+    fn nth_ancestor(&self, n: usize) -> Option<Self::Rebind<Any>> {
+        self.ancestors().nth(n)
+    }
+
+    /// Returns the parent of this object's parent, if any.
+    fn grandparent(&self) -> Option<Self::Rebind<Any>> {
+        self.nth_ancestor(1)
+    }
+
+    /// Returns the number of ancestors of this object.
+    ///
+    /// Root objects have a depth of zero, their children have a depth of one, and so on.
+    fn depth(&self) -> usize {
+        self.ancestors().count()
     }
 
     fn is_root(&self) -> bool {
@@ -28,10 +65,91 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
 
     fn children(&self) -> impl Iterator<Item = Self::Rebind<Any>>;
 
+    /// Iterates over all other objects which share the same parent as this one.
+    ///
+    /// Root objects have no siblings.
+    fn siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>>;
+
+    /// Iterates over this object and all other objects which share the same parent as this one,
+    /// in the order of their parent's [`Children`](bevy_hierarchy::Children).
+    ///
+    /// For root objects, this only yields the object itself.
+    fn self_and_siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>>;
+
+    /// Returns the index of this object within its parent's [`Children`](bevy_hierarchy::Children),
+    /// or `None` if this object is a root.
+    fn child_index(&self) -> Option<usize> {
+        let entity = self.entity();
+        self.parent()?
+            .children()
+            .position(|object| object.entity() == entity)
+    }
+
+    /// Returns the sibling immediately after this object in its parent's [`Children`](bevy_hierarchy::Children), if any.
+    fn next_sibling(&self) -> Option<Self::Rebind<Any>> {
+        let entity = self.entity();
+        let parent = self.parent()?;
+        let mut siblings = parent.children().map(|object| object.entity());
+        siblings.find(|&sibling| sibling == entity)?;
+        siblings.next().map(|sibling| self.rebind_any(sibling))
+    }
+
+    /// Returns the sibling immediately before this object in its parent's [`Children`](bevy_hierarchy::Children), if any.
+    fn previous_sibling(&self) -> Option<Self::Rebind<Any>> {
+        let entity = self.entity();
+        let parent = self.parent()?;
+        let mut previous = None;
+        for sibling in parent.children().map(|object| object.entity()) {
+            if sibling == entity {
+                return previous.map(|previous| self.rebind_any(previous));
+            }
+            previous = Some(sibling);
+        }
+        None
+    }
+
     fn has_children(&self) -> bool {
         self.children().next().is_some()
     }
 
+    /// Returns `true` if this object has no children.
+    fn is_leaf(&self) -> bool {
+        !self.has_children()
+    }
+
+    /// Returns the number of children of this object.
+    fn child_count(&self) -> usize {
+        self.children().count()
+    }
+
+    /// Returns the number of other objects which share the same parent as this one.
+    ///
+    /// Root objects have no siblings.
+    fn sibling_count(&self) -> usize {
+        self.parent()
+            .map_or(0, |parent| parent.child_count().saturating_sub(1))
+    }
+
+    /// Returns the children of this object, sorted using the given comparator.
+    ///
+    /// The sort is stable, so children which compare equal keep their [`Children`](bevy_hierarchy::Children) order.
+    fn children_sorted_by(
+        &self,
+        mut cmp: impl FnMut(&Self::Rebind<Any>, &Self::Rebind<Any>) -> Ordering,
+    ) -> Vec<Self::Rebind<Any>> {
+        let mut children: Vec<_> = self.children().collect();
+        children.sort_by(|a, b| cmp(a, b));
+        children
+    }
+
+    /// Returns the children of this object, sorted by name.
+    ///
+    /// Unnamed children are placed before named ones.
+    /// See [`children_sorted_by`](ObjectHierarchy::children_sorted_by) for more information.
+    fn children_sorted_by_name(&self) -> Vec<Self::Rebind<Any>> {
+        self.children_sorted_by(|a, b| a.name().cmp(&b.name()))
+    }
+
     fn query_children<'a, Q: QueryData, F: QueryFilter>(
         &'a self,
         query: &'a Query<'_, '_, Q, F>,
@@ -61,6 +179,14 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
         std::iter::once(self.rebind_any(self.entity())).chain(self.ancestors())
     }
 
+    /// Iterates over the ancestors of this object, up to but not including the given `stop` entity.
+    ///
+    /// If `stop` is not an ancestor of this object, this behaves like [`ancestors`](ObjectHierarchy::ancestors).
+    fn ancestors_until(&self, stop: Entity) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.ancestors()
+            .take_while(move |ancestor| ancestor.entity() != stop)
+    }
+
     fn is_ancestor_of(&self, entity: Entity) -> bool
     where
         Self::Rebind<Any>: ObjectHierarchy<Any>,
@@ -70,6 +196,24 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
             .any(|ancestor| ancestor.entity() == self.entity())
     }
 
+    /// Returns `true` if the given `entity` is a descendant of this object.
+    ///
+    /// This searches the entire subtree of this object, so it is O(n) in the number of descendants.
+    /// If you have the candidate as an object, [`is_descendant_of`](ObjectHierarchy::is_descendant_of)
+    /// is cheaper, since it only walks up the hierarchy.
+    fn contains_descendant(&self, entity: Entity) -> bool {
+        self.descendants_deep()
+            .any(|descendant| descendant.entity() == entity)
+    }
+
+    /// Returns `true` if this object is the given `entity` or one of its ancestors.
+    fn is_ancestor_or_self_of(&self, entity: Entity) -> bool
+    where
+        Self::Rebind<Any>: ObjectHierarchy<Any>,
+    {
+        self.entity() == entity || self.is_ancestor_of(entity)
+    }
+
     fn query_ancestors<'a, Q: QueryData, F: QueryFilter>(
         &'a self,
         query: &'a Query<'_, '_, Q, F>,
@@ -99,6 +243,19 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
             .map(|object| self.rebind_as(object.instance()))
     }
 
+    /// Iterates over the ancestors of this object while they are of [`Kind`] `U`, starting from its parent.
+    ///
+    /// Unlike [`ancestors_of_kind`](ObjectHierarchy::ancestors_of_kind), which skips ancestors which are not of
+    /// [`Kind`] `U`, this stops at the first such ancestor.
+    fn ancestors_of_kind_while<'a, U: Kind>(
+        &'a self,
+        objects: &'a Objects<'_, '_, U>,
+    ) -> impl Iterator<Item = Self::Rebind<U>> + 'a {
+        self.ancestors()
+            .map_while(move |object| objects.get(object.entity()).ok())
+            .map(|object| self.rebind_as(object.instance()))
+    }
+
     fn find_ancestor_of_kind<U: Kind>(
         &self,
         objects: &Objects<'_, '_, U>,
@@ -108,10 +265,119 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
             .map(|object| self.rebind_as(object.instance()))
     }
 
+    /// Returns the topmost object of [`Kind`] `U` among this object and its ancestors.
+    ///
+    /// This is useful for finding the outermost owner of an object in nested hierarchies.
+    fn root_of_kind<U: Kind>(&self, objects: &Objects<'_, '_, U>) -> Option<Self::Rebind<U>> {
+        self.self_and_ancestors()
+            .filter_map(|object| objects.get(object.entity()).ok())
+            .last()
+            .map(|object| self.rebind_as(object.instance()))
+    }
+
+    /// Returns the first ancestor of this object which satisfies the given predicate, starting from its parent.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_object::prelude::*;
+    ///
+    /// fn print_ui_roots(objects: Objects) {
+    ///     for object in objects.iter() {
+    ///         let ui_root = object.find_ancestor_where(|ancestor| {
+    ///             ancestor.name().is_some_and(|name| name.starts_with("UI"))
+    ///         });
+    ///         if let Some(ui_root) = ui_root {
+    ///             println!("{object:?} is under {ui_root:?}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn find_ancestor_where(
+        &self,
+        f: impl FnMut(&Self::Rebind<Any>) -> bool,
+    ) -> Option<Self::Rebind<Any>> {
+        self.ancestors().find(f)
+    }
+
     fn descendants_wide(&self) -> impl Iterator<Item = Self::Rebind<Any>>;
 
     fn descendants_deep(&self) -> impl Iterator<Item = Self::Rebind<Any>>;
 
+    /// Iterates over all descendants of this object in breadth-first order, along with their depth relative to this object.
+    ///
+    /// Direct children of this object have a depth of `1`.
+    fn descendants_with_depth(&self) -> impl Iterator<Item = (Self::Rebind<Any>, usize)> {
+        let mut queue: VecDeque<(Entity, usize)> =
+            self.children().map(|child| (child.entity(), 1)).collect();
+        std::iter::from_fn(move || {
+            let (entity, depth) = queue.pop_front()?;
+            let object = self.rebind_any(entity);
+            queue.extend(object.children().map(|child| (child.entity(), depth + 1)));
+            Some((object, depth))
+        })
+    }
+
+    /// Returns all descendants of this object grouped by their depth relative to this object.
+    ///
+    /// The first group contains the direct children of this object, the second contains their children, and so on.
+    /// Each group is in breadth-first order. This allocates a [`Vec`] for every level of the subtree.
+    fn descendants_by_level(&self) -> Vec<Vec<Self::Rebind<Any>>> {
+        let mut levels: Vec<Vec<Self::Rebind<Any>>> = Vec::new();
+        for (object, depth) in self.descendants_with_depth() {
+            if levels.len() < depth {
+                levels.push(Vec::new());
+            }
+            levels[depth - 1].push(object);
+        }
+        levels
+    }
+
+    /// Iterates over the descendants of this object in breadth-first order, up to the given depth.
+    ///
+    /// A `max_depth` of `0` yields nothing, and `1` yields only the direct children of this object.
+    fn descendants_wide_limited(
+        &self,
+        max_depth: usize,
+    ) -> impl Iterator<Item = Self::Rebind<Any>> {
+        let mut queue = VecDeque::new();
+        if max_depth > 0 {
+            queue.extend(self.children().map(|child| (child.entity(), 1)));
+        }
+        std::iter::from_fn(move || {
+            let (entity, depth) = queue.pop_front()?;
+            let object = self.rebind_any(entity);
+            if depth < max_depth {
+                queue.extend(object.children().map(|child| (child.entity(), depth + 1)));
+            }
+            Some(object)
+        })
+    }
+
+    /// Iterates over the descendants of this object in depth-first order, up to the given depth.
+    ///
+    /// A `max_depth` of `0` yields nothing, and `1` yields only the direct children of this object.
+    fn descendants_deep_limited(
+        &self,
+        max_depth: usize,
+    ) -> impl Iterator<Item = Self::Rebind<Any>> {
+        let mut stack = Vec::new();
+        if max_depth > 0 {
+            stack.extend(self.children().map(|child| (child.entity(), 1)));
+            stack.reverse();
+        }
+        std::iter::from_fn(move || {
+            let (entity, depth) = stack.pop()?;
+            let object = self.rebind_any(entity);
+            if depth < max_depth {
+                let start = stack.len();
+                stack.extend(object.children().map(|child| (child.entity(), depth + 1)));
+                stack[start..].reverse();
+            }
+            Some(object)
+        })
+    }
+
     fn self_and_descendants_wide(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
         std::iter::once(self.rebind_any(self.entity())).chain(self.descendants_wide())
     }
@@ -120,6 +386,202 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
         std::iter::once(self.rebind_any(self.entity())).chain(self.descendants_deep())
     }
 
+    /// Iterates over all descendants of this object in depth-first order, yielding each descendant at most once.
+    ///
+    /// Unlike [`descendants_deep`](ObjectHierarchy::descendants_deep), this does not assume the hierarchy is a tree.
+    /// If an entity is reachable through more than one path, or the hierarchy contains a cycle, it is only visited
+    /// the first time it is reached, and a warning is logged once.
+    ///
+    /// This is the robust option for untrusted hierarchies, such as those loaded from save files.
+    /// It allocates a [`HashSet`] of every visited entity, so prefer the unchecked variant for trusted hierarchies.
+    fn descendants_deep_checked(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        let mut visited = HashSet::from([self.entity()]);
+        let mut stack: Vec<Entity> = self.children().map(|child| child.entity()).collect();
+        stack.reverse();
+        std::iter::from_fn(move || {
+            while let Some(entity) = stack.pop() {
+                if !visited.insert(entity) {
+                    warn_once!(
+                        "malformed hierarchy: {entity} is reachable through more than one path"
+                    );
+                    continue;
+                }
+                let object = self.rebind_any(entity);
+                let start = stack.len();
+                stack.extend(object.children().map(|child| child.entity()));
+                stack[start..].reverse();
+                return Some(object);
+            }
+            None
+        })
+    }
+
+    /// Iterates over all descendants of this object in depth-first post-order.
+    ///
+    /// Unlike [`descendants_deep`](ObjectHierarchy::descendants_deep), each object is yielded after all of
+    /// its descendants. This is useful for bottom-up operations, such as releasing resources leaf-first.
+    fn descendants_deep_postorder(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        let mut stack: Vec<(Entity, bool)> = self
+            .children()
+            .map(|child| (child.entity(), false))
+            .collect();
+        stack.reverse();
+        std::iter::from_fn(move || {
+            while let Some((entity, visited)) = stack.pop() {
+                let object = self.rebind_any(entity);
+                if visited {
+                    return Some(object);
+                }
+                stack.push((entity, true));
+                let start = stack.len();
+                stack.extend(object.children().map(|child| (child.entity(), false)));
+                stack[start..].reverse();
+            }
+            None
+        })
+    }
+
+    /// Folds this object and all of its descendants into a single value, in depth-first order.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_object::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Weight(f32);
+    ///
+    /// fn total_weight(object: Object, weights: &Query<&Weight>) -> f32 {
+    ///     object.fold_descendants(0.0, |total, object| {
+    ///         total + weights.get(object.entity()).map_or(0.0, |weight| weight.0)
+    ///     })
+    /// }
+    /// ```
+    fn fold_descendants<B>(&self, init: B, mut f: impl FnMut(B, &Self::Rebind<Any>) -> B) -> B {
+        self.self_and_descendants_deep()
+            .fold(init, |acc, object| f(acc, &object))
+    }
+
+    /// Maps each child of this object using `f` and collects the results.
+    ///
+    /// This is equivalent to `self.children().map(|child| f(&child)).collect()`.
+    fn map_children<B>(&self, mut f: impl FnMut(&Self::Rebind<Any>) -> B) -> Vec<B> {
+        self.children().map(|object| f(&object)).collect()
+    }
+
+    /// Maps each descendant of this object using `f`, in depth-first order, and collects the results.
+    ///
+    /// This is equivalent to `self.descendants_deep().map(|object| f(&object)).collect()`.
+    fn map_descendants<B>(&self, mut f: impl FnMut(&Self::Rebind<Any>) -> B) -> Vec<B> {
+        self.descendants_deep().map(|object| f(&object)).collect()
+    }
+
+    /// Visits this object and all of its descendants in depth-first order, calling `on_enter` before
+    /// visiting the children of each object and `on_exit` after.
+    ///
+    /// Both callbacks receive the depth of the object relative to this one, which has a depth of `0`.
+    /// This is useful for stack-based algorithms which push state when entering an object and pop it when exiting.
+    fn walk(
+        &self,
+        mut on_enter: impl FnMut(&Self::Rebind<Any>, usize),
+        mut on_exit: impl FnMut(&Self::Rebind<Any>, usize),
+    ) {
+        let mut stack = vec![(self.entity(), 0, false)];
+        while let Some((entity, depth, exit)) = stack.pop() {
+            let object = self.rebind_any(entity);
+            if exit {
+                on_exit(&object, depth);
+                continue;
+            }
+            on_enter(&object, depth);
+            stack.push((entity, depth, true));
+            let start = stack.len();
+            stack.extend(
+                object
+                    .children()
+                    .map(|child| (child.entity(), depth + 1, false)),
+            );
+            stack[start..].reverse();
+        }
+    }
+
+    /// Calls `f` with the [`Entity`] of this object and each of its descendants, in depth-first order.
+    ///
+    /// This is useful for applying the same commands to an entire subtree.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_object::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Frozen;
+    ///
+    /// fn freeze(object: Object, commands: &mut Commands) {
+    ///     object.for_each_descendant(commands, |entity, commands| {
+    ///         commands.entity(entity).insert(Frozen);
+    ///     });
+    /// }
+    /// ```
+    fn for_each_descendant(
+        &self,
+        commands: &mut Commands,
+        mut f: impl FnMut(Entity, &mut Commands),
+    ) {
+        for object in self.self_and_descendants_deep() {
+            f(object.entity(), commands);
+        }
+    }
+
+    /// Iterates over all descendants of this object which satisfy the given predicate, in depth-first order.
+    ///
+    /// The descendants of objects which do not satisfy the predicate are still visited.
+    fn descendants_where(
+        &self,
+        keep: impl FnMut(&Self::Rebind<Any>) -> bool,
+    ) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.descendants_deep().filter(keep)
+    }
+
+    /// Returns the lowest common ancestor of this object and `other`, or `None` if they are not in the same hierarchy.
+    ///
+    /// If one object is an ancestor of the other, it is the common ancestor of both.
+    fn common_ancestor<U: Kind>(
+        &self,
+        other: &impl ObjectHierarchy<U>,
+    ) -> Option<Self::Rebind<Any>> {
+        let ancestors: HashSet<Entity> = self
+            .self_and_ancestors()
+            .map(|object| object.entity())
+            .collect();
+        other
+            .self_and_ancestors()
+            .map(|object| object.entity())
+            .find(|entity| ancestors.contains(entity))
+            .map(|entity| self.rebind_any(entity))
+    }
+
+    /// Returns the number of parent/child edges between this object and `other`,
+    /// or `None` if they are not in the same hierarchy.
+    ///
+    /// This is the number of steps from this object up to the [`common_ancestor`](ObjectHierarchy::common_ancestor)
+    /// of both objects, plus the number of steps from there down to `other`.
+    fn distance_to<U: Kind>(&self, other: &impl ObjectHierarchy<U>) -> Option<usize> {
+        let ancestors: Vec<Entity> = self
+            .self_and_ancestors()
+            .map(|object| object.entity())
+            .collect();
+        other
+            .self_and_ancestors()
+            .enumerate()
+            .find_map(|(down, object)| {
+                let up = ancestors
+                    .iter()
+                    .position(|&entity| entity == object.entity())?;
+                Some(up + down)
+            })
+    }
+
     fn is_descendant_of(&self, entity: Entity) -> bool
     where
         Self::Rebind<Any>: ObjectHierarchy<Any>,
@@ -127,6 +589,57 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
         self.ancestors().any(|ancestor| ancestor.entity() == entity)
     }
 
+    /// Returns `true` if this object is the given `entity` or one of its descendants.
+    fn is_descendant_or_self_of(&self, entity: Entity) -> bool
+    where
+        Self::Rebind<Any>: ObjectHierarchy<Any>,
+    {
+        self.entity() == entity || self.is_descendant_of(entity)
+    }
+
+    /// Returns `true` if this object is a descendant of the given `ancestor` object.
+    ///
+    /// This is the same as [`is_descendant_of`](ObjectHierarchy::is_descendant_of), but takes an object instead of an [`Entity`].
+    fn is_descendant_of_object<U: Kind>(&self, ancestor: &impl ObjectHierarchy<U>) -> bool
+    where
+        Self::Rebind<Any>: ObjectHierarchy<Any>,
+    {
+        self.is_descendant_of(ancestor.entity())
+    }
+
+    /// Returns `true` if this object and `other` have the same parent and are not the same object.
+    ///
+    /// Root objects have no parent, so they are never siblings.
+    fn is_sibling_of<U: Kind>(&self, other: &impl ObjectHierarchy<U>) -> bool {
+        self.entity() != other.entity()
+            && self
+                .parent()
+                .is_some_and(|parent| other.is_child_of(parent.entity()))
+    }
+
+    /// Returns the [`Relationship`] of this object to `other`.
+    ///
+    /// If more than one relationship applies, the most specific one is returned, in order of precedence:
+    /// [`Same`](Relationship::Same), [`Ancestor`](Relationship::Ancestor), [`Descendant`](Relationship::Descendant),
+    /// [`Sibling`](Relationship::Sibling), and finally [`Unrelated`](Relationship::Unrelated).
+    fn relationship_to<U: Kind>(&self, other: &impl ObjectHierarchy<U>) -> Relationship
+    where
+        Self::Rebind<Any>: ObjectHierarchy<Any>,
+    {
+        let entity = other.entity();
+        if self.entity() == entity {
+            Relationship::Same
+        } else if self.is_ancestor_of(entity) {
+            Relationship::Ancestor
+        } else if self.is_descendant_of(entity) {
+            Relationship::Descendant
+        } else if self.is_sibling_of(other) {
+            Relationship::Sibling
+        } else {
+            Relationship::Unrelated
+        }
+    }
+
     fn descendants_of_kind_wide<'a, U: Kind>(
         &'a self,
         objects: &'a Objects<'_, '_, U>,
@@ -145,6 +658,67 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
             .map(|object| self.rebind_as(object.instance()))
     }
 
+    /// Returns the number of descendants of this object.
+    ///
+    /// This visits the entire subtree of this object.
+    fn descendant_count(&self) -> usize {
+        self.descendants_deep().count()
+    }
+
+    /// Returns the [`Entity`] of every descendant of this object, in depth-first order.
+    ///
+    /// This allocates a snapshot of the subtree, which is useful when the hierarchy is about to be
+    /// modified (e.g. by despawning) while it is being traversed.
+    fn subtree_entities(&self) -> Vec<Entity> {
+        self.descendants_deep()
+            .map(|object| object.entity())
+            .collect()
+    }
+
+    /// Returns the [`Entity`] of this object and every one of its descendants, in depth-first order.
+    ///
+    /// See [`subtree_entities`](ObjectHierarchy::subtree_entities) for more information.
+    fn self_and_subtree_entities(&self) -> Vec<Entity> {
+        self.self_and_descendants_deep()
+            .map(|object| object.entity())
+            .collect()
+    }
+
+    /// Appends the [`Entity`] of every descendant of this object to `buffer`, in breadth-first order.
+    ///
+    /// Existing contents of `buffer` are kept. The buffer itself is used as the traversal queue,
+    /// so no other memory is allocated. If the same buffer is cleared and reused across frames,
+    /// repeated traversals do not allocate once it has grown to fit the largest subtree.
+    /// The tradeoff is that the buffer retains its peak capacity until it is dropped or shrunk.
+    fn descendants_into(&self, buffer: &mut Vec<Entity>) {
+        let start = buffer.len();
+        buffer.extend(self.children().map(|child| child.entity()));
+        let mut index = start;
+        while index < buffer.len() {
+            let object = self.rebind_any(buffer[index]);
+            buffer.extend(object.children().map(|child| child.entity()));
+            index += 1;
+        }
+    }
+
+    /// Iterates over all descendants of this object which have no children, in depth-first order.
+    fn leaves(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.descendants_deep()
+            .filter(|object| !object.has_children())
+    }
+
+    /// Iterates over all leaf descendants of this object which are of [`Kind`] `U`, in depth-first order.
+    ///
+    /// See [`leaves`](ObjectHierarchy::leaves) for more information.
+    fn leaves_of_kind<'a, U: Kind>(
+        &'a self,
+        objects: &'a Objects<'_, '_, U>,
+    ) -> impl Iterator<Item = Self::Rebind<U>> + 'a {
+        self.leaves()
+            .filter_map(move |object| objects.get(object.entity()).ok())
+            .map(|object| self.rebind_as(object.instance()))
+    }
+
     fn query_descendants_wide<'a, Q: QueryData, F: QueryFilter>(
         &'a self,
         query: &'a Query<'_, '_, Q, F>,
@@ -203,6 +777,171 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
             .map(|object| self.rebind_as(object.instance()))
     }
 
+    /// Returns the first descendant of this object which satisfies the given predicate, in breadth-first order.
+    ///
+    /// See [`find_ancestor_where`](ObjectHierarchy::find_ancestor_where) for an example.
+    fn find_descendant_where_wide(
+        &self,
+        f: impl FnMut(&Self::Rebind<Any>) -> bool,
+    ) -> Option<Self::Rebind<Any>> {
+        self.descendants_wide().find(f)
+    }
+
+    /// Returns the first descendant of this object which satisfies the given predicate, in depth-first order.
+    ///
+    /// See [`find_ancestor_where`](ObjectHierarchy::find_ancestor_where) for an example.
+    fn find_descendant_where_deep(
+        &self,
+        f: impl FnMut(&Self::Rebind<Any>) -> bool,
+    ) -> Option<Self::Rebind<Any>> {
+        self.descendants_deep().find(f)
+    }
+
+    /// Writes this object and all of its descendants to the given formatter as an indented tree.
+    ///
+    /// Each object is written on its own line using its [`Debug`](fmt::Debug) format,
+    /// indented by two spaces per level below this object.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        Self::Rebind<Any>: fmt::Debug,
+    {
+        let mut stack = vec![(self.entity(), 0)];
+        while let Some((entity, depth)) = stack.pop() {
+            let object = self.rebind_any(entity);
+            writeln!(f, "{:indent$}{object:?}", "", indent = depth * 2)?;
+            let start = stack.len();
+            stack.extend(object.children().map(|child| (child.entity(), depth + 1)));
+            stack[start..].reverse();
+        }
+        Ok(())
+    }
+
+    /// Prints this object and all of its descendants to the standard output as an indented tree.
+    ///
+    /// See [`fmt_tree`](ObjectHierarchy::fmt_tree) for more information.
+    fn print_tree(&self)
+    where
+        Self::Rebind<Any>: fmt::Debug,
+    {
+        struct Tree<'a, T: Kind, H: ObjectHierarchy<T>>(&'a H, PhantomData<T>);
+
+        impl<T: Kind, H: ObjectHierarchy<T>> fmt::Display for Tree<'_, T, H>
+        where
+            H::Rebind<Any>: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt_tree(f)
+            }
+        }
+
+        print!("{}", Tree(self, PhantomData));
+    }
+
+    /// Returns the path to this object from its root.
+    ///
+    /// The path consists of the names of all objects from the root down to this one, separated by slashes.
+    /// Unnamed objects are represented by `*`.
+    fn path(&self) -> String {
+        self.path_with_separator("/")
+    }
+
+    /// Returns the path to this object from its root, using the given separator instead of a slash.
+    ///
+    /// See [`path`](ObjectHierarchy::path) for more information.
+    fn path_with_separator(&self, separator: &str) -> String {
+        let objects: SmallVec<[Self::Rebind<Any>; PATH_INLINE_DEPTH]> =
+            self.self_and_ancestors().collect();
+        join_path(path_names(&objects), separator)
+    }
+
+    /// Iterates over the names of all objects from the root down to this one.
+    ///
+    /// Unnamed objects are represented by `*`.
+    ///
+    /// This is useful for inspecting or comparing paths segment by segment without building the full
+    /// [`path`](ObjectHierarchy::path) string. All ancestors are collected up front, since they
+    /// are visited from this object up to the root. Each name is copied as its segment is yielded.
+    fn path_segments(&self) -> impl Iterator<Item = String> {
+        let objects: SmallVec<[Self::Rebind<Any>; PATH_INLINE_DEPTH]> =
+            self.self_and_ancestors().collect();
+        objects
+            .into_iter()
+            .rev()
+            .map(|object| object.name().unwrap_or("*").to_owned())
+    }
+
+    /// Returns the path to this object from its root, with at most `max_depth` segments.
+    ///
+    /// If this object has more than `max_depth` segments in its path, the path is truncated to the last
+    /// `max_depth` segments, preceded by `...`. Otherwise, this is the same as [`path`](ObjectHierarchy::path).
+    ///
+    /// Unlike [`path`](ObjectHierarchy::path), this method stops walking up the hierarchy once `max_depth` is reached.
+    fn bounded_path(&self, max_depth: usize) -> String {
+        let mut ancestors = self.self_and_ancestors();
+        let objects: SmallVec<[Self::Rebind<Any>; PATH_INLINE_DEPTH]> =
+            ancestors.by_ref().take(max_depth).collect();
+        let names = path_names(&objects);
+        if ancestors.next().is_some() {
+            join_path(std::iter::once("...").chain(names), "/")
+        } else {
+            join_path(names, "/")
+        }
+    }
+
+    /// Returns the path from this object to `other`, or `None` if they are not in the same hierarchy.
+    ///
+    /// The path starts with a `..` segment for each step up to the lowest common ancestor of both
+    /// objects, followed by the names of the objects leading down to `other`.
+    /// If an object shares its name with any of its siblings, it is written as `Name[n]`,
+    /// where `n` is its index among the children with that name.
+    /// If both objects are the same, the path is `.`.
+    ///
+    /// The result may be passed to [`find_by_path`](ObjectHierarchy::find_by_path) on this object to find `other`.
+    /// If any object below the common ancestor on the way to `other` is unnamed, there is no such path and `None` is returned.
+    fn path_to<U: Kind>(&self, other: &impl ObjectHierarchy<U>) -> Option<String> {
+        let ancestors: Vec<Entity> = self
+            .self_and_ancestors()
+            .map(|object| object.entity())
+            .collect();
+        let mut names = Vec::new();
+        for object in other.self_and_ancestors() {
+            if let Some(up) = ancestors
+                .iter()
+                .position(|&entity| entity == object.entity())
+            {
+                let mut segments = vec!["..".to_owned(); up];
+                segments.extend(names.into_iter().rev());
+                if segments.is_empty() {
+                    return Some(".".to_owned());
+                }
+                return Some(segments.join("/"));
+            }
+            names.push(unique_segment(&object)?);
+        }
+        None
+    }
+
+    /// Returns the path to this object relative to the given `ancestor`.
+    ///
+    /// If an object shares its name with any of its siblings, it is written as `Name[n]`,
+    /// where `n` is its index among the children with that name.
+    /// The result may be passed to [`find_by_path`](ObjectHierarchy::find_by_path) on `ancestor` to find this object.
+    /// If `ancestor` is this object, the path is empty.
+    ///
+    /// Returns `None` if `ancestor` is not an ancestor of this object, or if any object
+    /// below `ancestor` on the way to this object is unnamed.
+    fn path_relative_to<U: Kind>(&self, ancestor: &impl ObjectHierarchy<U>) -> Option<String> {
+        let mut names = Vec::new();
+        for object in self.self_and_ancestors() {
+            if object.entity() == ancestor.entity() {
+                names.reverse();
+                return Some(names.join("/"));
+            }
+            names.push(unique_segment(&object)?);
+        }
+        None
+    }
+
     /// Attempts to find an object by its path, relative to this one.
     ///
     /// # Usage
@@ -214,6 +953,11 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
     ///   - `.` represents this object.
     ///   - `..` represents the parent object.
     ///   - `*` represents any child object.
+    ///   - `[n]` represents the child object at index `n`.
+    ///   - `Name[n]` represents the child object at index `n` among all children with the given name.
+    ///
+    /// Indices start at zero and follow the order of the [`Children`](bevy_hierarchy::Children) component.
+    /// If multiple children share the same name, each of them is tried in order until the rest of the path is found.
     ///
     /// Note that this method of object search is relatively slow, and should be reserved for
     /// when performance is not the top priority, such as during initialization or prototyping.
@@ -223,7 +967,55 @@ pub trait ObjectHierarchy<T: Kind = Any>: ObjectRebind<T> + ObjectName {
     /// # Safety
     /// This method is somewhat experimental with plans for future expansion.
     /// Please [report](https://github.com/Zeenobit/moonshine_object/issues) any bugs you encounter or features you'd like.
-    fn find_by_path(&self, path: impl AsRef<str>) -> Option<Self::Rebind<Any>>;
+    fn find_by_path(&self, path: impl AsRef<str>) -> Option<Self::Rebind<Any>> {
+        self.find_by(&ObjectPath::new(path.as_ref()))
+    }
+
+    /// Attempts to find an object by its path, relative to this one, using the given separator instead of a slash.
+    ///
+    /// See [`find_by_path`](ObjectHierarchy::find_by_path) for more information, and
+    /// [`ObjectPath::with_separator`] for how separators which overlap with `.` and `..` are parsed.
+    ///
+    /// # Panics
+    /// Panics if `separator` is empty, or if it contains `*`, `[` or `]`.
+    fn find_by_path_with_separator(
+        &self,
+        path: impl AsRef<str>,
+        separator: &str,
+    ) -> Option<Self::Rebind<Any>> {
+        self.find_by(&ObjectPath::with_separator(path.as_ref(), separator))
+    }
+
+    /// Attempts to find an object by a pre-parsed [`ObjectPath`], relative to this one.
+    ///
+    /// This is the same as [`find_by_path`](ObjectHierarchy::find_by_path), except that the path is
+    /// only parsed once, which makes it better suited for paths which are resolved frequently.
+    fn find_by(&self, path: &ObjectPath) -> Option<Self::Rebind<Any>>;
+
+    /// Attempts to find an object by its path, relative to this one, and returns all objects visited along the way.
+    ///
+    /// The trail contains one object for each `..`, `*`, name, or index segment in the path,
+    /// ending with the object found. `.` segments do not add to the trail.
+    ///
+    /// See [`find_by_path`](ObjectHierarchy::find_by_path) for more information.
+    fn find_by_path_trail(&self, path: impl AsRef<str>) -> Option<Vec<Self::Rebind<Any>>>;
+}
+
+/// Describes how one object relates to another within a hierarchy.
+///
+/// See [`ObjectHierarchy::relationship_to`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Relationship {
+    /// Both objects are the same.
+    Same,
+    /// This object is an ancestor of the other.
+    Ancestor,
+    /// This object is a descendant of the other.
+    Descendant,
+    /// Both objects share the same parent.
+    Sibling,
+    /// None of the above.
+    Unrelated,
 }
 
 impl<T: Kind> ObjectHierarchy<T> for Object<'_, '_, '_, T> {
@@ -233,12 +1025,37 @@ impl<T: Kind> ObjectHierarchy<T> for Object<'_, '_, '_, T> {
             .map(|entity| self.rebind_any(entity))
     }
 
+    fn root_entity(&self) -> Entity {
+        let entity = self.entity();
+        self.hierarchy.ancestors(entity).last().unwrap_or(entity)
+    }
+
     fn children(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
         self.hierarchy
             .children(self.entity())
             .map(|entity| self.rebind_any(entity))
     }
 
+    fn siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        let entity = self.entity();
+        self.hierarchy
+            .parent(entity)
+            .into_iter()
+            .flat_map(|parent| self.hierarchy.children(parent))
+            .filter(move |&sibling| sibling != entity)
+            .map(|entity| self.rebind_any(entity))
+    }
+
+    fn self_and_siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        let entity = self.entity();
+        let parent = self.hierarchy.parent(entity);
+        parent
+            .into_iter()
+            .flat_map(|parent| self.hierarchy.children(parent))
+            .chain(parent.is_none().then_some(entity))
+            .map(|entity| self.rebind_any(entity))
+    }
+
     fn ancestors(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
         self.hierarchy
             .ancestors(self.entity())
@@ -257,9 +1074,20 @@ impl<T: Kind> ObjectHierarchy<T> for Object<'_, '_, '_, T> {
             .map(|entity| self.rebind_any(entity))
     }
 
-    fn find_by_path(&self, path: impl AsRef<str>) -> Option<Self::Rebind<Any>> {
-        let tail: Vec<&str> = path.as_ref().split('/').collect();
-        find_by_path(self.cast_into_any(), &tail)
+    fn find_by(&self, path: &ObjectPath) -> Option<Self::Rebind<Any>> {
+        find_by_segments(self.cast_into_any(), path.segments(), None)
+    }
+
+    fn find_by_path_trail(&self, path: impl AsRef<str>) -> Option<Vec<Self::Rebind<Any>>> {
+        let path = ObjectPath::new(path.as_ref());
+        let mut trail = Vec::new();
+        find_by_segments(self.cast_into_any(), path.segments(), Some(&mut trail))?;
+        Some(
+            trail
+                .into_iter()
+                .map(|entity| self.rebind_any(entity))
+                .collect(),
+        )
     }
 }
 
@@ -268,10 +1096,24 @@ impl<T: Kind> ObjectHierarchy<T> for ObjectRef<'_, '_, '_, T> {
         self.1.parent().map(|object| ObjectRef(self.0, object))
     }
 
+    fn root_entity(&self) -> Entity {
+        self.1.root_entity()
+    }
+
     fn children(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
         self.1.children().map(|object| ObjectRef(self.0, object))
     }
 
+    fn siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.siblings().map(|object| ObjectRef(self.0, object))
+    }
+
+    fn self_and_siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1
+            .self_and_siblings()
+            .map(|object| ObjectRef(self.0, object))
+    }
+
     fn ancestors(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
         self.1.ancestors().map(|object| ObjectRef(self.0, object))
     }
@@ -288,45 +1130,216 @@ impl<T: Kind> ObjectHierarchy<T> for ObjectRef<'_, '_, '_, T> {
             .map(|object| ObjectRef(self.0, object))
     }
 
-    fn find_by_path(&self, path: impl AsRef<str>) -> Option<Self::Rebind<Any>> {
-        self.1
-            .find_by_path(path)
-            .map(|object| ObjectRef(self.0, object))
+    fn find_by(&self, path: &ObjectPath) -> Option<Self::Rebind<Any>> {
+        self.1.find_by(path).map(|object| ObjectRef(self.0, object))
+    }
+
+    fn find_by_path_trail(&self, path: impl AsRef<str>) -> Option<Vec<Self::Rebind<Any>>> {
+        self.1.find_by_path_trail(path).map(|trail| {
+            trail
+                .into_iter()
+                .map(|object| ObjectRef(self.0, object))
+                .collect()
+        })
+    }
+}
+
+impl<T: Component> ObjectHierarchy<T> for ObjectMut<'_, '_, '_, T> {
+    fn parent(&self) -> Option<Self::Rebind<Any>> {
+        self.1.parent()
+    }
+
+    fn root_entity(&self) -> Entity {
+        self.1.root_entity()
+    }
+
+    fn children(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.children()
+    }
+
+    fn siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.siblings()
+    }
+
+    fn self_and_siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.self_and_siblings()
+    }
+
+    fn ancestors(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.ancestors()
+    }
+
+    fn descendants_wide(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.descendants_wide()
+    }
+
+    fn descendants_deep(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.descendants_deep()
+    }
+
+    fn find_by(&self, path: &ObjectPath) -> Option<Self::Rebind<Any>> {
+        self.1.find_by(path)
+    }
+
+    fn find_by_path_trail(&self, path: impl AsRef<str>) -> Option<Vec<Self::Rebind<Any>>> {
+        self.1.find_by_path_trail(path)
+    }
+}
+
+impl<T: Kind> ObjectHierarchy<T> for ObjectRefMut<'_, '_, '_, T> {
+    fn parent(&self) -> Option<Self::Rebind<Any>> {
+        self.1.parent()
+    }
+
+    fn root_entity(&self) -> Entity {
+        self.1.root_entity()
+    }
+
+    fn children(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.children()
+    }
+
+    fn siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.siblings()
+    }
+
+    fn self_and_siblings(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.self_and_siblings()
+    }
+
+    fn ancestors(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.ancestors()
+    }
+
+    fn descendants_wide(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.descendants_wide()
+    }
+
+    fn descendants_deep(&self) -> impl Iterator<Item = Self::Rebind<Any>> {
+        self.1.descendants_deep()
+    }
+
+    fn find_by(&self, path: &ObjectPath) -> Option<Self::Rebind<Any>> {
+        self.1.find_by(path)
+    }
+
+    fn find_by_path_trail(&self, path: impl AsRef<str>) -> Option<Vec<Self::Rebind<Any>>> {
+        self.1.find_by_path_trail(path)
     }
 }
 
-fn find_by_path<T: ObjectHierarchy<Rebind<Any> = T>>(
+/// Resolves the given path segments, starting from `curr`.
+///
+/// If a `trail` is given, the entity of each object visited along the way is pushed into it.
+/// The trail is left unchanged if the path cannot be resolved.
+fn find_by_segments<T: ObjectHierarchy<Rebind<Any> = T>>(
     curr: T,
-    tail: &[&str],
+    segments: &[ObjectPathSegment],
+    mut trail: Option<&mut Vec<Entity>>,
 ) -> Option<T::Rebind<Any>> {
-    if tail.is_empty() {
+    let Some((head, tail)) = segments.split_first() else {
         return Some(curr);
+    };
+
+    match head {
+        ObjectPathSegment::Self_ => find_by_segments(curr, tail, trail),
+        ObjectPathSegment::Parent => find_next(curr.parent()?, tail, trail),
+        ObjectPathSegment::Wildcard => curr
+            .children()
+            .find_map(|child| find_next(child, tail, trail.as_deref_mut())),
+        ObjectPathSegment::Name(name) => curr
+            .children()
+            .filter(|part| part.name().is_some_and(|part_name| part_name == name))
+            .find_map(|child| find_next(child, tail, trail.as_deref_mut())),
+        ObjectPathSegment::Index(index) => find_next(curr.children().nth(*index)?, tail, trail),
+        ObjectPathSegment::NameIndex(name, index) => {
+            let child = curr
+                .children()
+                .filter(|part| part.name().is_some_and(|part_name| part_name == name))
+                .nth(*index)?;
+            find_next(child, tail, trail)
+        }
     }
+}
 
-    let head = tail[0];
-    let tail = &tail[1..];
+/// Returns the path segment which identifies the given object among its siblings, or `None` if it is unnamed.
+///
+/// If any sibling has the same name, the segment is indexed as `Name[n]`.
+/// Iterates over the names of the given objects, from the last one to the first.
+fn path_names<T: ObjectName>(objects: &[T]) -> impl Clone + Iterator<Item = &str> {
+    objects
+        .iter()
+        .rev()
+        .map(|object| object.name().unwrap_or("*"))
+}
 
-    if head == "." || head.is_empty() {
-        find_by_path(curr, tail)
-    } else if head == ".." {
-        if let Some(parent) = curr.parent() {
-            find_by_path(parent, tail)
-        } else {
-            None
+/// Joins the given names into a single path, separated by `separator`.
+fn join_path<'a>(names: impl Clone + Iterator<Item = &'a str>, separator: &str) -> String {
+    let (len, count) = names.clone().fold((0, 0usize), |(len, count), name| {
+        (len + name.len(), count + 1)
+    });
+    let mut path = String::with_capacity(len + separator.len() * count.saturating_sub(1));
+    for (index, name) in names.enumerate() {
+        if index > 0 {
+            path.push_str(separator);
+        }
+        path.push_str(name);
+    }
+    path
+}
+
+fn unique_segment<T: Kind>(object: &impl ObjectHierarchy<T>) -> Option<String> {
+    object.name()?;
+    Some(indexed_segment(object).to_string())
+}
+
+/// Returns the path segment which identifies the given object among its siblings.
+///
+/// Named objects are indexed as `Name[n]` only if any sibling has the same name.
+/// Unnamed objects are indexed as `[n]` among all siblings, or `*` if they are a root.
+pub(crate) fn indexed_segment<T: Kind>(object: &impl ObjectHierarchy<T>) -> ObjectPathSegment {
+    let entity = object.entity();
+    let Some(name) = object.name() else {
+        if object.is_root() {
+            return ObjectPathSegment::Wildcard;
         }
-    } else if head == "*" {
-        for child in curr.children() {
-            if let Some(result) = find_by_path(child, tail) {
-                return Some(result);
+        let index = object
+            .self_and_siblings()
+            .position(|sibling| sibling.entity() == entity)
+            .unwrap_or_default();
+        return ObjectPathSegment::Index(index);
+    };
+    let mut index = 0;
+    let mut count = 0;
+    for sibling in object.self_and_siblings() {
+        if sibling.name() == Some(name) {
+            if sibling.entity() == entity {
+                index = count;
             }
+            count += 1;
         }
-        return None;
-    } else if let Some(child) = curr
-        .children()
-        .find(|part| part.name().is_some_and(|name| name == head))
-    {
-        find_by_path(child, tail)
+    }
+    if count > 1 {
+        ObjectPathSegment::NameIndex(name.to_owned(), index)
     } else {
-        None
+        ObjectPathSegment::Name(name.to_owned())
+    }
+}
+
+/// Moves to `next` and resolves the remaining path segments from there.
+fn find_next<T: ObjectHierarchy<Rebind<Any> = T>>(
+    next: T,
+    tail: &[ObjectPathSegment],
+    mut trail: Option<&mut Vec<Entity>>,
+) -> Option<T::Rebind<Any>> {
+    if let Some(trail) = trail.as_deref_mut() {
+        trail.push(next.entity());
+    }
+    let result = find_by_segments(next, tail, trail.as_deref_mut());
+    if result.is_none() {
+        if let Some(trail) = trail {
+            trail.pop();
+        }
     }
+    result
 }
@@ -0,0 +1,167 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use bevy_app::prelude::*;
+use bevy_core::Name;
+use bevy_ecs::{prelude::*, query::QueryFilter};
+use bevy_hierarchy::{Children, Parent};
+use moonshine_kind::{prelude::*, Any};
+use moonshine_util::hierarchy::HierarchyQuery;
+
+use crate::{Object, ObjectHierarchy, ObjectPath, ObjectPathSegment, ObjectRebind, Objects};
+
+/// A [`Plugin`] which maintains an [`ObjectNameIndex`].
+///
+/// The index is updated in [`Last`]. Only parents whose children, or whose children's
+/// [`Name`] or [`Parent`], have changed since the last update are re-indexed.
+pub struct ObjectNameIndexPlugin;
+
+impl Plugin for ObjectNameIndexPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ObjectNameIndex>()
+            .add_systems(Last, update_object_name_index);
+    }
+}
+
+/// A [`Resource`] which maps each parent [`Entity`] and child [`Name`] to the first child with that name.
+///
+/// This is maintained by the [`ObjectNameIndexPlugin`] and is used by
+/// [`Objects::find_by_path_indexed`] to resolve paths without searching through children.
+///
+/// The index is only updated once per frame, so it may be stale within a frame.
+#[derive(Resource, Default, Debug)]
+pub struct ObjectNameIndex {
+    children: HashMap<Entity, HashMap<String, Entity>>,
+    parents: HashMap<Entity, Entity>,
+}
+
+impl ObjectNameIndex {
+    /// Returns the first child of `parent` with the given `name`, if it was indexed.
+    pub fn get(&self, parent: Entity, name: &str) -> Option<Entity> {
+        self.children.get(&parent)?.get(name).copied()
+    }
+
+    /// Returns `true` if nothing is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Removes all entries from the index.
+    pub fn clear(&mut self) {
+        self.children.clear();
+        self.parents.clear();
+    }
+
+    /// Replaces all entries of the given `parent` with its current named `children`.
+    fn update(
+        &mut self,
+        parent: Entity,
+        children: impl Iterator<Item = Entity>,
+        names: &Query<&Name>,
+    ) {
+        if let Some(previous) = self.children.remove(&parent) {
+            for child in previous.into_values() {
+                if self.parents.get(&child) == Some(&parent) {
+                    self.parents.remove(&child);
+                }
+            }
+        }
+        let mut indexed = HashMap::new();
+        for child in children {
+            let Ok(name) = names.get(child) else {
+                continue;
+            };
+            if let Entry::Vacant(entry) = indexed.entry(name.as_str().to_owned()) {
+                entry.insert(child);
+                self.parents.insert(child, parent);
+            }
+        }
+        if !indexed.is_empty() {
+            self.children.insert(parent, indexed);
+        }
+    }
+}
+
+type IndexChanged = Or<(Changed<Name>, Changed<Parent>, Changed<Children>)>;
+
+fn update_object_name_index(
+    mut index: ResMut<ObjectNameIndex>,
+    changed: Query<Entity, IndexChanged>,
+    mut removed_names: RemovedComponents<Name>,
+    mut removed_parents: RemovedComponents<Parent>,
+    mut removed_children: RemovedComponents<Children>,
+    hierarchy: HierarchyQuery,
+    names: Query<&Name>,
+) {
+    let mut dirty = HashSet::new();
+
+    // A changed entity may be a parent whose children changed, or a child whose name or parent changed.
+    for entity in changed.iter() {
+        dirty.insert(entity);
+        dirty.extend(hierarchy.parent(entity));
+    }
+
+    // Removed children leave their parent with nothing to index.
+    dirty.extend(removed_children.read());
+
+    // A child which lost its name or parent, or was despawned, is evicted from its previous parent.
+    for entity in removed_names.read().chain(removed_parents.read()) {
+        dirty.extend(index.parents.get(&entity).copied());
+        dirty.extend(hierarchy.parent(entity));
+    }
+
+    for parent in dirty {
+        index.update(parent, hierarchy.children(parent), &names);
+    }
+}
+
+impl<'w, 's, T, F> Objects<'w, 's, T, F>
+where
+    T: Kind,
+    F: 'static + QueryFilter,
+{
+    /// Attempts to find an object by its path, relative to the given [`Entity`], using an [`ObjectNameIndex`].
+    ///
+    /// Named segments are resolved with a hash lookup instead of searching through children.
+    /// If the path contains wildcard or index segments, or if the index misses or is stale,
+    /// this falls back to [`find_by_path`](ObjectHierarchy::find_by_path).
+    ///
+    /// Returns `None` if `entity` is not an [`Object`] of [`Kind`] `T`, or if no object matches the path.
+    pub fn find_by_path_indexed(
+        &self,
+        index: &ObjectNameIndex,
+        entity: Entity,
+        path: impl AsRef<str>,
+    ) -> Option<Object<'w, 's, '_, Any>> {
+        let object = self.get(entity).ok()?;
+        let path = ObjectPath::new(path.as_ref());
+        match self.find_indexed(index, entity, path.segments()) {
+            Some(found) => Some(object.rebind_any(found)),
+            None => object.find_by(&path),
+        }
+    }
+
+    fn find_indexed(
+        &self,
+        index: &ObjectNameIndex,
+        mut curr: Entity,
+        segments: &[ObjectPathSegment],
+    ) -> Option<Entity> {
+        for segment in segments {
+            curr = match segment {
+                ObjectPathSegment::Self_ => curr,
+                ObjectPathSegment::Parent => self.hierarchy.parent(curr)?,
+                ObjectPathSegment::Name(name) => {
+                    let child = index.get(curr, name)?;
+                    let valid = self.hierarchy.parent(child) == Some(curr)
+                        && self.name.get(child).is_ok_and(|n| n.as_str() == name);
+                    if !valid {
+                        return None;
+                    }
+                    child
+                }
+                _ => return None,
+            };
+        }
+        Some(curr)
+    }
+}
@@ -1,7 +1,7 @@
 use bevy_ecs::prelude::*;
 use moonshine_kind::{prelude::*, Any};
 
-use crate::{Object, ObjectRef};
+use crate::{Object, ObjectMut, ObjectRef, ObjectRefMut};
 
 pub trait ObjectInstance<T: Kind = Any> {
     /// Returns the [`Instance`] of this object.
@@ -40,3 +40,15 @@ impl<T: Kind> ObjectInstance<T> for ObjectRef<'_, '_, '_, T> {
         self.1.instance()
     }
 }
+
+impl<T: Component> ObjectInstance<T> for ObjectMut<'_, '_, '_, T> {
+    fn instance(&self) -> Instance<T> {
+        self.1.instance()
+    }
+}
+
+impl<T: Kind> ObjectInstance<T> for ObjectRefMut<'_, '_, '_, T> {
+    fn instance(&self) -> Instance<T> {
+        self.1.instance()
+    }
+}
@@ -1,20 +1,33 @@
 #![doc = include_str!("../README.md")]
 
-use std::{fmt, ops::Deref};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
 
 use bevy_core::Name;
 use bevy_ecs::{
+    component::ComponentId,
+    entity::Entities,
     prelude::*,
-    query::{QueryEntityError, QueryFilter, QuerySingleError},
-    system::SystemParam,
+    ptr::Ptr,
+    query::{QueryEntityError, QueryFilter, QueryParIter, QuerySingleError, WorldQuery},
+    system::{SystemParam, SystemState},
+    world::EntityMutExcept,
 };
-use bevy_hierarchy::Parent;
-use moonshine_kind::prelude::*;
+use bevy_hierarchy::{Children, Parent};
+use moonshine_kind::{prelude::*, InstanceRef};
 use moonshine_util::hierarchy::HierarchyQuery;
 
 pub mod prelude {
-    pub use super::{Object, ObjectRef, Objects};
-    pub use super::{ObjectHierarchy, ObjectInstance, ObjectName, ObjectRebind};
+    pub use super::{LeafObjects, Object, ObjectMut, ObjectPath, ObjectPathRef, ObjectRef};
+    pub use super::{ObjectCommands, ObjectHierarchy, ObjectInstance, ObjectName, ObjectRebind};
+    pub use super::{ObjectEntityMut, ObjectRefMut, Objects, ObjectsMut};
+    pub use super::{ObjectHierarchyChanged, ObjectHierarchyPlugin};
+    pub use super::{ObjectNameIndex, ObjectNameIndexPlugin};
 }
 
 pub use moonshine_kind::{Any, CastInto, Kind};
@@ -30,8 +43,12 @@ where
     pub root: Query<'w, 's, Instance<T>, (F, Without<Parent>)>,
     pub hierarchy: HierarchyQuery<'w, 's>,
     pub name: Query<'w, 's, &'static Name>,
+    entities: &'w Entities,
 }
 
+/// An [`Objects`] which only matches objects without any [`Children`].
+pub type LeafObjects<'w, 's, T = Any, F = ()> = Objects<'w, 's, T, (F, Without<Children>)>;
+
 impl<'w, 's, T, F> Objects<'w, 's, T, F>
 where
     T: Kind,
@@ -46,7 +63,23 @@ where
         })
     }
 
-    /// Iterates over all [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`.
+    /// Iterates over all [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`,
+    /// each paired with a display label.
+    ///
+    /// The label is the [`Name`] of the object, or `Entity(...)` with its [`Entity`] if it is unnamed.
+    pub fn iter_labeled(&self) -> impl Iterator<Item = (Object<'w, 's, '_, T>, String)> {
+        self.iter().map(|object| {
+            let label = match object.name() {
+                Some(name) => name.to_owned(),
+                None => format!("Entity({})", object.entity()),
+            };
+            (object, label)
+        })
+    }
+
+    /// Iterates over all root [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`.
+    ///
+    /// An object is a root if it has no parent.
     pub fn iter_root(&self) -> impl Iterator<Item = Object<'w, 's, '_, T>> {
         self.root.iter().map(|instance| Object {
             instance,
@@ -55,6 +88,146 @@ where
         })
     }
 
+    /// Iterates over all root [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`,
+    /// each followed by all of its descendants.
+    ///
+    /// Unlike [`iter_root`](Objects::iter_root), the order of iteration is deterministic:
+    /// Roots are sorted by [`Entity`], and the descendants of each root are visited depth-first
+    /// in the order of their [`Children`](bevy_hierarchy::Children).
+    ///
+    /// This is useful when a reproducible order is required, such as for tests or exports.
+    pub fn iter_forest_ordered(&self) -> impl Iterator<Item = Object<'w, 's, '_>> {
+        let mut roots: Vec<Entity> = self.root.iter().map(|instance| instance.entity()).collect();
+        roots.sort();
+        roots
+            .into_iter()
+            .flat_map(|root| std::iter::once(root).chain(self.hierarchy.descendants_deep(root)))
+            .map(|entity| Object {
+                instance: Instance::from(entity),
+                hierarchy: &self.hierarchy,
+                name: &self.name,
+            })
+    }
+
+    /// Iterates over all descendants of the given [`Entity`] which are [`Object`]s of [`Kind`] `T`, in depth-first order.
+    ///
+    /// The given entity itself does not need to be of [`Kind`] `T`.
+    /// If it does not exist or has no children, the iterator is empty.
+    pub fn iter_descendants_of(
+        &self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Object<'w, 's, '_, T>> {
+        self.hierarchy
+            .descendants_deep(entity)
+            .filter_map(move |descendant| self.get(descendant).ok())
+    }
+
+    /// Iterates over all unique combinations of `K` [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`.
+    ///
+    /// This is the [`Objects`] equivalent of [`Query::iter_combinations`].
+    /// Each combination is yielded once, regardless of order, and never contains the same object twice.
+    pub fn iter_combinations<const K: usize>(
+        &self,
+    ) -> impl Iterator<Item = [Object<'w, 's, '_, T>; K]> {
+        self.instance.iter_combinations::<K>().map(|instances| {
+            instances.map(|instance| Object {
+                instance,
+                hierarchy: &self.hierarchy,
+                name: &self.name,
+            })
+        })
+    }
+
+    /// Returns a parallel iterator over all [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`.
+    ///
+    /// See [`ObjectsParIter::for_each`] for more information.
+    pub fn par_iter(&self) -> ObjectsParIter<'w, 's, '_, T, F> {
+        ObjectsParIter {
+            iter: self.instance.par_iter(),
+            hierarchy: &self.hierarchy,
+            name: &self.name,
+        }
+    }
+
+    /// Returns the first [`Object`] of [`Kind`] `T` which satisfies the given predicate.
+    ///
+    /// This is equivalent to `objects.iter().find(f)`.
+    pub fn find(
+        &self,
+        f: impl FnMut(&Object<'w, 's, '_, T>) -> bool,
+    ) -> Option<Object<'w, 's, '_, T>> {
+        self.iter().find(f)
+    }
+
+    /// Returns `true` if any [`Object`] of [`Kind`] `T` satisfies the given predicate.
+    ///
+    /// This is equivalent to `objects.iter().any(f)`.
+    pub fn any(&self, mut f: impl FnMut(&Object<'w, 's, '_, T>) -> bool) -> bool {
+        self.iter().any(|object| f(&object))
+    }
+
+    /// Returns `true` if every [`Object`] of [`Kind`] `T` satisfies the given predicate.
+    ///
+    /// This is equivalent to `objects.iter().all(f)`, and returns `true` if there are no objects.
+    pub fn all(&self, mut f: impl FnMut(&Object<'w, 's, '_, T>) -> bool) -> bool {
+        self.iter().all(|object| f(&object))
+    }
+
+    /// Returns the component `C` of the given [`Object`] from an existing [`Query`], if present.
+    ///
+    /// This is useful for reading components of an [`Object`] without access to the [`World`].
+    pub fn component<'a, C: Component>(
+        &'a self,
+        object: &Object<T>,
+        query: &'a Query<'_, '_, &C>,
+    ) -> Option<&'a C> {
+        query.get(object.entity()).ok()
+    }
+
+    /// Iterates over the [`Object`]s of [`Kind`] `T` for each of the given entities.
+    ///
+    /// Entities which do not match are skipped.
+    pub fn iter_many(
+        &self,
+        entities: impl IntoIterator<Item = Entity>,
+    ) -> impl Iterator<Item = Object<'w, 's, '_, T>> {
+        entities
+            .into_iter()
+            .filter_map(move |entity| self.get(entity).ok())
+    }
+
+    /// Iterates over all [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`,
+    /// each paired with a pointer to its component with the given [`ComponentId`], if present.
+    ///
+    /// This is useful for type-erased component access, such as from scripting runtimes.
+    ///
+    /// The returned [`Ptr`] is untyped. It is only safe to dereference it as the type of the
+    /// component which was registered with the given [`ComponentId`].
+    pub fn iter_dyn<'a>(
+        &'a self,
+        world: &'a World,
+        component_id: ComponentId,
+    ) -> impl Iterator<Item = (Object<'w, 's, 'a, T>, Option<Ptr<'a>>)> {
+        self.iter().map(move |object| {
+            let ptr = world.entity(object.entity()).get_by_id(component_id).ok();
+            (object, ptr)
+        })
+    }
+
+    /// Returns the number of [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`.
+    ///
+    /// This is O(n), since it iterates over every matching entity.
+    pub fn len(&self) -> usize {
+        self.instance.iter().count()
+    }
+
+    /// Returns `true` if there are no [`Object`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`.
+    ///
+    /// This is cheaper than checking if [`len`](Objects::len) is zero.
+    pub fn is_empty(&self) -> bool {
+        self.instance.is_empty()
+    }
+
     pub fn contains(&self, entity: Entity) -> bool {
         self.instance.contains(entity)
     }
@@ -75,7 +248,7 @@ where
         &'a self,
         world: &'a World,
     ) -> impl Iterator<Item = ObjectRef<'w, 's, 'a, T>> {
-        self.iter()
+        self.iter_root()
             .map(|object: Object<T>| ObjectRef(world.entity(object.entity()), object))
     }
 
@@ -88,6 +261,33 @@ where
         })
     }
 
+    /// Gets any [`Entity`] as an [`Object<Any>`], regardless of whether it is of [`Kind`] `T`.
+    ///
+    /// Returns `None` if the entity does not exist.
+    /// This is useful for diagnostics which need to report on entities rejected by [`get`](Objects::get).
+    pub fn get_any(&self, entity: Entity) -> Option<Object<'w, 's, '_, Any>> {
+        self.entities.contains(entity).then(|| Object {
+            instance: Instance::from(entity),
+            hierarchy: &self.hierarchy,
+            name: &self.name,
+        })
+    }
+
+    /// Gets the [`Object`]s of [`Kind`] `T` for each of the given entities, if they all match.
+    ///
+    /// Returns an error if any of the entities do not match.
+    pub fn get_many<'a, const N: usize>(
+        &'a self,
+        entities: [Entity; N],
+    ) -> Result<[Object<'w, 's, 'a, T>; N], QueryEntityError<'a>> {
+        let instances = self.instance.get_many(entities)?;
+        Ok(instances.map(|instance| Object {
+            instance,
+            hierarchy: &self.hierarchy,
+            name: &self.name,
+        }))
+    }
+
     pub fn get_root(&self, entity: Entity) -> Result<Object<'w, 's, '_, T>, QueryEntityError> {
         self.root.get(entity).map(|instance| Object {
             instance,
@@ -100,6 +300,29 @@ where
         Some(ObjectRef(entity, self.get(entity.id()).ok()?))
     }
 
+    /// Gets the [`ObjectRefMut`] of [`Kind`] `T` from an [`ObjectEntityMut`], if it matches.
+    ///
+    /// Unlike an [`EntityMut`], an [`ObjectEntityMut`] may be queried alongside [`Objects`],
+    /// because it excludes the hierarchy and name components which [`Objects`] reads.
+    pub fn get_mut_ref<'a>(
+        &'a self,
+        entity: ObjectEntityMut<'a>,
+    ) -> Option<ObjectRefMut<'w, 's, 'a, T>> {
+        let object = self.get(entity.id()).ok()?;
+        Some(ObjectRefMut(entity, object))
+    }
+
+    /// Gets the [`ObjectRef`] of [`Kind`] `T` from an [`Entity`], if it matches.
+    ///
+    /// This is the same as [`get_ref`](Objects::get_ref), except that the [`EntityRef`] is resolved from the given [`World`].
+    pub fn get_ref_by_entity<'a>(
+        &'a self,
+        world: &'a World,
+        entity: Entity,
+    ) -> Option<ObjectRef<'w, 's, 'a, T>> {
+        self.get_ref(world.get_entity(entity).ok()?)
+    }
+
     pub fn get_single(&self) -> Result<Object<'w, 's, '_, T>, QuerySingleError> {
         self.instance.get_single().map(|instance| Object {
             instance,
@@ -108,6 +331,52 @@ where
         })
     }
 
+    /// Returns the only [`Object`] of [`Kind`] `T` which matches the [`QueryFilter`] `F`.
+    ///
+    /// This is the panicking form of [`get_single`](Objects::get_single).
+    ///
+    /// # Panics
+    /// If there is not exactly one matching object.
+    pub fn single(&self) -> Object<'w, 's, '_, T> {
+        match self.get_single() {
+            Ok(object) => object,
+            Err(QuerySingleError::NoEntities(_)) => {
+                panic!(
+                    "expected exactly one {} object, found none",
+                    T::debug_name()
+                )
+            }
+            Err(QuerySingleError::MultipleEntities(_)) => {
+                panic!(
+                    "expected exactly one {} object, found many",
+                    T::debug_name()
+                )
+            }
+        }
+    }
+
+    /// Returns the only [`Object`] of [`Kind`] `T` within the subtree of the given `root`.
+    ///
+    /// The subtree includes `root` itself and all of its descendants.
+    /// Returns an error if there is not exactly one matching object within the subtree.
+    pub fn get_single_in_subtree(
+        &self,
+        root: Entity,
+    ) -> Result<Object<'w, 's, '_, T>, QuerySingleError> {
+        let mut objects = std::iter::once(root)
+            .chain(self.hierarchy.descendants_deep(root))
+            .filter_map(|entity| self.get(entity).ok());
+        let Some(object) = objects.next() else {
+            return Err(QuerySingleError::NoEntities(std::any::type_name::<Self>()));
+        };
+        if objects.next().is_some() {
+            return Err(QuerySingleError::MultipleEntities(std::any::type_name::<
+                Self,
+            >()));
+        }
+        Ok(object)
+    }
+
     pub fn get_single_root(&self) -> Result<Object<'w, 's, '_, T>, QuerySingleError> {
         self.root.get_single().map(|instance| Object {
             instance,
@@ -120,6 +389,48 @@ where
         Some(ObjectRef(entity, self.get_single().ok()?))
     }
 
+    /// Returns the [`ObjectRef`] of the only [`Object`] of [`Kind`] `T` which matches the [`QueryFilter`] `F`.
+    ///
+    /// Unlike [`get_single_ref`](Objects::get_single_ref), the [`EntityRef`] is resolved from the given [`World`].
+    pub fn single_ref_in<'a>(
+        &'a self,
+        world: &'a World,
+    ) -> Result<ObjectRef<'w, 's, 'a, T>, QuerySingleError> {
+        let object = self.get_single()?;
+        Ok(ObjectRef(world.entity(object.entity()), object))
+    }
+
+    /// Returns the [`Entity`] of the only [`Object`] of [`Kind`] `T` which matches the [`QueryFilter`] `F`.
+    ///
+    /// This is cheaper than [`get_single`](Objects::get_single) if only the entity is needed.
+    pub fn single_entity(&self) -> Result<Entity, QuerySingleError> {
+        self.instance.get_single().map(|instance| instance.entity())
+    }
+
+    /// Returns the [`HierarchyQuery`] used by these objects to traverse their hierarchy.
+    ///
+    /// This is an escape hatch for custom traversal algorithms which are not provided by [`ObjectHierarchy`].
+    pub fn hierarchy(&self) -> &HierarchyQuery<'w, 's> {
+        &self.hierarchy
+    }
+
+    /// Returns the [`Entity`] of every [`Object`] of [`Kind`] `T` which has at least one child
+    /// that is not of [`Kind`] `U`.
+    ///
+    /// This is a diagnostic tool intended for validating hierarchy invariants in tests and tooling,
+    /// such as "every child of a menu must be a menu item". It visits every child of every object,
+    /// so avoid using it in performance critical systems.
+    pub fn validate_children_kinds<U: Kind>(&self, children: &Objects<'_, '_, U>) -> Vec<Entity> {
+        self.iter()
+            .filter(|object| {
+                object
+                    .children()
+                    .any(|child| !children.contains(child.entity()))
+            })
+            .map(|object| object.entity())
+            .collect()
+    }
+
     /// Gets the [`Object`] of [`Kind`] `T` from an [`Instance`].
     ///
     /// # Safety
@@ -129,6 +440,90 @@ where
     }
 }
 
+/// A parallel iterator over [`Object`]s, created by [`Objects::par_iter`].
+pub struct ObjectsParIter<'w, 's, 'a, T: Kind, F: 'static + QueryFilter> {
+    iter: QueryParIter<'a, 'a, Instance<T>, F>,
+    hierarchy: &'a HierarchyQuery<'w, 's>,
+    name: &'a Query<'w, 's, &'static Name>,
+}
+
+impl<'w, 's, 'a, T: Kind, F: 'static + QueryFilter> ObjectsParIter<'w, 's, 'a, T, F> {
+    /// Runs `f` on each [`Object`] in parallel, using the [`ComputeTaskPool`].
+    ///
+    /// # Panics
+    /// If the [`ComputeTaskPool`] is not initialized.
+    /// This should never happen when used from a system run by the ECS scheduler.
+    ///
+    /// [`ComputeTaskPool`]: https://docs.rs/bevy/latest/bevy/tasks/struct.ComputeTaskPool.html
+    pub fn for_each(self, f: impl Fn(Object<'w, 's, 'a, T>) + Send + Sync + Clone) {
+        let Self {
+            iter,
+            hierarchy,
+            name,
+        } = self;
+        iter.for_each(move |instance| {
+            f(Object {
+                instance,
+                hierarchy,
+                name,
+            })
+        });
+    }
+}
+
+/// A [`SystemParam`] similar to [`Objects`] which provides mutable access to the component of [`Kind`] `T`.
+///
+/// The component is accessed separately from the hierarchy and names, which remain read-only.
+/// As a result, this may not be used with [`Parent`], [`Children`], or [`Name`] as `T`.
+#[derive(SystemParam)]
+pub struct ObjectsMut<'w, 's, T, F = ()>
+where
+    T: Component,
+    F: 'static + QueryFilter,
+{
+    pub instance: Query<'w, 's, (Instance<T>, &'static mut T), F>,
+    pub hierarchy: HierarchyQuery<'w, 's>,
+    pub name: Query<'w, 's, &'static Name>,
+}
+
+impl<'w, 's, T, F> ObjectsMut<'w, 's, T, F>
+where
+    T: Component,
+    F: 'static + QueryFilter,
+{
+    /// Iterates over all [`ObjectMut`]s of [`Kind`] `T` which match the [`QueryFilter`] `F`.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ObjectMut<'w, 's, '_, T>> {
+        let hierarchy = &self.hierarchy;
+        let name = &self.name;
+        self.instance.iter_mut().map(move |(instance, data)| {
+            ObjectMut(
+                data,
+                Object {
+                    instance,
+                    hierarchy,
+                    name,
+                },
+            )
+        })
+    }
+
+    /// Gets the [`ObjectMut`] of [`Kind`] `T` from an [`Entity`], if it matches.
+    pub fn get_mut<'a>(
+        &'a mut self,
+        entity: Entity,
+    ) -> Result<ObjectMut<'w, 's, 'a, T>, QueryEntityError<'a>> {
+        let (instance, data) = self.instance.get_mut(entity)?;
+        Ok(ObjectMut(
+            data,
+            Object {
+                instance,
+                hierarchy: &self.hierarchy,
+                name: &self.name,
+            },
+        ))
+    }
+}
+
 /// Represents an [`Entity`] of [`Kind`] `T` with hierarchy and name information.
 pub struct Object<'w, 's, 'a, T: Kind = Any> {
     instance: Instance<T>,
@@ -150,6 +545,98 @@ impl<'w, 's, 'a, T: Kind> Object<'w, 's, 'a, T> {
             name: base.name,
         }
     }
+
+    /// Creates a new [`Object<T>`] from an [`Object<Any>`], if it matches the [`Kind::Filter`] of `T`.
+    ///
+    /// Unlike [`from_base`](Object::from_base), this works for any [`Kind`], not just components.
+    ///
+    /// Only the archetype of the entity is checked, so change detection filters such as
+    /// [`Added`] or [`Changed`] are treated as [`With`].
+    /// Returns `None` if any component referenced by the filter is not registered in the [`World`].
+    pub fn from_base_of_kind(world: &World, base: Object<'w, 's, 'a>) -> Option<Self> {
+        let entity = world.get_entity(base.entity()).ok()?;
+        let state = <T::Filter as WorldQuery>::get_state(world.components())?;
+        let archetype = entity.archetype();
+        if !<T::Filter as WorldQuery>::matches_component_set(&state, &|id| archetype.contains(id)) {
+            return None;
+        }
+        // SAFE: Entity was just checked to match the filter of T.
+        Some(unsafe { Self::from_base_unchecked(base) })
+    }
+
+    /// Returns a copy of this object which borrows its queries for the lifetime of `&self`.
+    ///
+    /// An [`Object`] borrows the hierarchy and name queries of the [`Objects`] which created it.
+    /// This is useful when passing an object into a function or helper struct which requires that
+    /// borrow to be no longer than a local reference, such as when storing it alongside other borrowed data.
+    pub fn reborrow(&self) -> Object<'w, 's, '_, T> {
+        Object {
+            instance: self.instance,
+            hierarchy: self.hierarchy,
+            name: self.name,
+        }
+    }
+
+    /// Returns the [`Name`] of this object, or the debug name of its [`Kind`] if it has none.
+    ///
+    /// This is useful for logging unnamed objects.
+    pub fn name_or_kind(&self) -> String {
+        self.name().map_or_else(T::debug_name, str::to_owned)
+    }
+
+    /// Returns the [`EntityRef`] of this object from the given [`World`].
+    ///
+    /// # Panics
+    /// If the entity of this object has been despawned.
+    pub fn entity_ref<'e>(&self, world: &'e World) -> EntityRef<'e> {
+        world.entity(self.entity())
+    }
+
+    /// Returns the component `C` of this object from the given [`World`], if present.
+    ///
+    /// # Panics
+    /// If the entity of this object has been despawned.
+    pub fn get_component<'e, C: Component>(&self, world: &'e World) -> Option<&'e C> {
+        self.entity_ref(world).get::<C>()
+    }
+
+    /// Returns the [`Instance<U>`] of this object, if it has the component `U`.
+    ///
+    /// This is a lighter alternative to [`from_base`](Object::from_base) when only the instance is needed.
+    /// Returns `None` if the entity of this object has been despawned.
+    pub fn as_instance_of<U: Component>(&self, world: &World) -> Option<Instance<U>> {
+        let entity = world.get_entity(self.entity()).ok()?;
+        Instance::<U>::from_entity(entity)
+    }
+
+    /// Queues a command which reorders the [`Children`] of this object using the given comparator.
+    ///
+    /// Unlike sorting during iteration, this changes the order of children in the hierarchy itself.
+    /// The children are re-read and sorted when the command is applied.
+    pub fn sort_children_by(
+        &self,
+        commands: &mut Commands,
+        mut cmp: impl FnMut(&Object, &Object) -> Ordering + Send + Sync + 'static,
+    ) {
+        let entity = self.entity();
+        commands.queue(move |world: &mut World| {
+            let mut state = SystemState::<Objects>::new(world);
+            let objects = state.get(world);
+            let Ok(object) = objects.get(entity) else {
+                return;
+            };
+            let mut children: Vec<Object> = object.children().collect();
+            children.sort_by(|a, b| cmp(a, b));
+            let order: HashMap<Entity, usize> = children
+                .iter()
+                .enumerate()
+                .map(|(index, child)| (child.entity(), index))
+                .collect();
+            if let Some(mut children) = world.get_mut::<Children>(entity) {
+                children.sort_by_key(|child| order.get(child).copied());
+            }
+        });
+    }
 }
 
 impl<'w, 's, 'a, T: Component> Object<'w, 's, 'a, T> {
@@ -189,32 +676,80 @@ impl<T: Kind> PartialEq for Object<'_, '_, '_, T> {
 
 impl<T: Kind> Eq for Object<'_, '_, '_, T> {}
 
+impl<T: Kind> Hash for Object<'_, '_, '_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entity().hash(state);
+    }
+}
+
+/// Objects are ordered by their [`Entity`], not by their position in the hierarchy or their name.
+impl<T: Kind> PartialOrd for Object<'_, '_, '_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Objects are ordered by their [`Entity`], not by their position in the hierarchy or their name.
+impl<T: Kind> Ord for Object<'_, '_, '_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.entity().cmp(&other.entity())
+    }
+}
+
+/// The alternate format (`{:#?}`) also includes the [`path`](ObjectHierarchy::path) of the object.
 impl<T: Kind> fmt::Debug for Object<'_, '_, '_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = f.alternate().then(|| self.path());
         let mut out = f.debug_tuple(&T::debug_name());
         out.field(&self.entity());
         if let Some(name) = self.name() {
             out.field(&name);
         }
+        if let Some(path) = path {
+            out.field(&path);
+        }
         out.finish()
     }
 }
 
 pub struct ObjectRef<'w, 's, 'a, T: Kind = Any>(EntityRef<'a>, Object<'w, 's, 'a, T>);
 
+/// # Panics
+///
+/// Panics if the entity does not have a `T` component.
+/// This may happen if `T` is a [`Kind`] which is not itself the component being queried.
+/// Use [`ObjectRef::get`] to access components fallibly.
 impl<T: Component> Deref for ObjectRef<'_, '_, '_, T> {
     type Target = T;
 
+    #[track_caller]
     fn deref(&self) -> &Self::Target {
-        self.0.get::<T>().unwrap()
+        match self.0.get::<T>() {
+            Some(component) => component,
+            None => missing_component::<T>(self.0.id()),
+        }
     }
 }
 
 impl<'w, 's, 'a, T: Kind> ObjectRef<'w, 's, 'a, T> {
+    /// Returns the [`Name`] of this object, or the debug name of its [`Kind`] if it has none.
+    ///
+    /// This is useful for logging unnamed objects.
+    pub fn name_or_kind(&self) -> String {
+        self.name().map_or_else(T::debug_name, str::to_owned)
+    }
+
     pub fn get<U: Component>(&self) -> Option<&U> {
         self.0.get::<U>()
     }
 
+    /// Returns a [`Ref`] to the component `U` of this object, if present.
+    ///
+    /// Unlike [`get`](ObjectRef::get), this preserves change detection information.
+    pub fn get_ref<U: Component>(&self) -> Option<Ref<'a, U>> {
+        self.0.get_ref::<U>()
+    }
+
     pub fn contains<U: Component>(&self) -> bool {
         self.0.contains::<U>()
     }
@@ -230,6 +765,16 @@ impl<'w, 's, 'a, T: Kind> ObjectRef<'w, 's, 'a, T> {
     }
 }
 
+impl<'w, 's, 'a, T: Component> ObjectRef<'w, 's, 'a, T> {
+    /// Returns an [`InstanceRef`] to the component of [`Kind`] `T` on this object.
+    ///
+    /// This is useful for passing objects to code which expects an [`InstanceRef`].
+    /// Returns `None` if the component is not present.
+    pub fn instance_ref(&self) -> Option<InstanceRef<'a, T>> {
+        InstanceRef::from_entity(self.0)
+    }
+}
+
 impl<T: Kind> Clone for ObjectRef<'_, '_, '_, T> {
     fn clone(&self) -> Self {
         *self
@@ -270,20 +815,170 @@ impl<T: Kind> PartialEq for ObjectRef<'_, '_, '_, T> {
 
 impl<T: Kind> Eq for ObjectRef<'_, '_, '_, T> {}
 
+impl<T: Kind> Hash for ObjectRef<'_, '_, '_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state);
+    }
+}
+
+/// Objects are ordered by their [`Entity`], not by their position in the hierarchy or their name.
+impl<T: Kind> PartialOrd for ObjectRef<'_, '_, '_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Objects are ordered by their [`Entity`], not by their position in the hierarchy or their name.
+impl<T: Kind> Ord for ObjectRef<'_, '_, '_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
 impl<T: Kind> fmt::Debug for ObjectRef<'_, '_, '_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.1.fmt(f)
     }
 }
 
+/// Mutable access to all components of an [`Entity`], except [`Parent`], [`Children`] and [`Name`].
+///
+/// This may be queried in the same system as [`Objects`]. See [`Objects::get_mut_ref`].
+pub type ObjectEntityMut<'a> = EntityMutExcept<'a, (Parent, Children, Name)>;
+
+/// Similar to [`ObjectRef`], but with mutable access to the components of its [`Entity`].
+///
+/// The [`Parent`], [`Children`] and [`Name`] components are not accessible through this type,
+/// since they are read by the hierarchy and name queries of [`Objects`].
+pub struct ObjectRefMut<'w, 's, 'a, T: Kind = Any>(ObjectEntityMut<'a>, Object<'w, 's, 'a, T>);
+
+/// # Panics
+///
+/// Panics if the entity does not have a `T` component.
+impl<T: Component> Deref for ObjectRefMut<'_, '_, '_, T> {
+    type Target = T;
+
+    #[track_caller]
+    fn deref(&self) -> &Self::Target {
+        match self.0.get::<T>() {
+            Some(component) => component,
+            None => missing_component::<T>(self.0.id()),
+        }
+    }
+}
+
+/// # Panics
+///
+/// Panics if the entity does not have a `T` component.
+impl<T: Component> DerefMut for ObjectRefMut<'_, '_, '_, T> {
+    #[track_caller]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let entity = self.0.id();
+        match self.0.get_mut::<T>() {
+            Some(component) => component.into_inner(),
+            None => missing_component::<T>(entity),
+        }
+    }
+}
+
+#[cold]
+#[track_caller]
+fn missing_component<T: Component>(entity: Entity) -> ! {
+    panic!(
+        "cannot dereference object {entity}: it has no {} component; \
+         use `get` to access components which may be missing",
+        std::any::type_name::<T>()
+    )
+}
+
+impl<T: Kind> ObjectRefMut<'_, '_, '_, T> {
+    pub fn get<U: Component>(&self) -> Option<&U> {
+        self.0.get::<U>()
+    }
+
+    pub fn get_mut<U: Component>(&mut self) -> Option<Mut<'_, U>> {
+        self.0.get_mut::<U>()
+    }
+
+    /// Returns `true` if this object has the component `U`.
+    ///
+    /// This always returns `false` for [`Parent`], [`Children`] and [`Name`].
+    pub fn contains<U: Component>(&self) -> bool {
+        self.0.get::<U>().is_some()
+    }
+}
+
+impl<'w, 's, 'a, T: Kind> From<ObjectRefMut<'w, 's, 'a, T>> for Object<'w, 's, 'a, T> {
+    fn from(object: ObjectRefMut<'w, 's, 'a, T>) -> Self {
+        object.1
+    }
+}
+
+impl<'w, 's, 'a, T: Kind> From<&ObjectRefMut<'w, 's, 'a, T>> for Object<'w, 's, 'a, T> {
+    fn from(object: &ObjectRefMut<'w, 's, 'a, T>) -> Self {
+        object.1
+    }
+}
+
+impl<T: Kind> fmt::Debug for ObjectRefMut<'_, '_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+/// Represents an [`Object`] of [`Kind`] `T` with mutable access to its component.
+///
+/// See [`ObjectsMut`] for more information.
+pub struct ObjectMut<'w, 's, 'a, T: Component>(Mut<'a, T>, Object<'w, 's, 'a, T>);
+
+impl<T: Component> Deref for ObjectMut<'_, '_, '_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Component> DerefMut for ObjectMut<'_, '_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'w, 's, 'a, T: Component> From<ObjectMut<'w, 's, 'a, T>> for Object<'w, 's, 'a, T> {
+    fn from(object: ObjectMut<'w, 's, 'a, T>) -> Self {
+        object.1
+    }
+}
+
+impl<'w, 's, 'a, T: Component> From<&ObjectMut<'w, 's, 'a, T>> for Object<'w, 's, 'a, T> {
+    fn from(object: &ObjectMut<'w, 's, 'a, T>) -> Self {
+        object.1
+    }
+}
+
+impl<T: Component> fmt::Debug for ObjectMut<'_, '_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(f)
+    }
+}
+
+mod commands;
 mod hierarchy;
+mod index;
 mod instance;
 mod name;
+mod path;
+mod plugin;
 mod rebind;
 
+pub use commands::*;
 pub use hierarchy::*;
+pub use index::*;
 pub use instance::*;
 pub use name::*;
+pub use path::*;
+pub use plugin::*;
 pub use rebind::*;
 
 #[cfg(test)]
@@ -292,6 +987,21 @@ mod tests {
 
     use bevy::{ecs::system::RunSystemOnce, prelude::*};
 
+    //     A
+    //    /
+    //   B
+    //  / \
+    // C   D
+    fn spawn_sample_tree(w: &mut World) -> (Entity, Entity, Entity, Entity) {
+        let a = w.spawn(Name::new("A")).id();
+        let b = w.spawn(Name::new("B")).id();
+        let c = w.spawn(Name::new("C")).id();
+        let d = w.spawn(Name::new("D")).id();
+        w.entity_mut(a).add_children(&[b]);
+        w.entity_mut(b).add_children(&[c, d]);
+        (a, b, c, d)
+    }
+
     #[test]
     fn find_by_path() {
         let mut w = World::new();
@@ -473,4 +1183,1946 @@ mod tests {
             })
             .unwrap());
     }
+
+    #[test]
+    fn path_to() {
+        let mut w = World::new();
+        let (a, _, c, d) = spawn_sample_tree(&mut w);
+        let e = w.spawn(Name::new("E")).id();
+
+        w.run_system_once(move |objects: Objects| {
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            assert_eq!(c.path_to(&d).unwrap(), "../D");
+            assert_eq!(c.path_to(&c).unwrap(), ".");
+            assert_eq!(c.find_by_path(c.path_to(&d).unwrap()), Some(d));
+            assert_eq!(objects.get(a).unwrap().path_to(&d).unwrap(), "B/D");
+            assert_eq!(d.path_to(&objects.get(a).unwrap()).unwrap(), "../..");
+            assert_eq!(c.path_to(&objects.get(e).unwrap()), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn path_relative_to() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let c = objects.get(c).unwrap();
+            assert_eq!(c.path_relative_to(&a).unwrap(), "B/C");
+            assert_eq!(c.path_relative_to(&b).unwrap(), "C");
+            assert_eq!(c.path_relative_to(&c).unwrap(), "");
+            assert_eq!(a.find_by_path(c.path_relative_to(&a).unwrap()), Some(c));
+            assert_eq!(b.path_relative_to(&c), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn bounded_path() {
+        let mut w = World::new();
+        let (_, _, c, _) = spawn_sample_tree(&mut w);
+        let mut leaf = c;
+        for name in ["E", "F", "G"] {
+            let child = w.spawn(Name::new(name)).id();
+            w.entity_mut(leaf).add_child(child);
+            leaf = child;
+        }
+
+        w.run_system_once(move |objects: Objects| {
+            let leaf = objects.get(leaf).unwrap();
+            assert_eq!(leaf.path(), "A/B/C/E/F/G");
+            assert!(leaf
+                .path_segments()
+                .eq(["A", "B", "C", "E", "F", "G"].map(String::from)));
+            assert_eq!(leaf.bounded_path(3), ".../E/F/G");
+            assert_eq!(leaf.bounded_path(1), ".../G");
+            assert_eq!(leaf.bounded_path(0), "...");
+            assert_eq!(leaf.bounded_path(5), ".../B/C/E/F/G");
+            assert_eq!(leaf.bounded_path(6), leaf.path());
+            assert_eq!(leaf.bounded_path(usize::MAX), leaf.path());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn find_by_path_index() {
+        let mut w = World::new();
+
+        //      A
+        //    / | \
+        //   B  *  B
+        //  / \
+        // *   *
+
+        let a = w.spawn(Name::new("A")).id();
+        let b0 = w.spawn(Name::new("B")).id();
+        let x = w.spawn_empty().id();
+        let b1 = w.spawn(Name::new("B")).id();
+        let y = w.spawn_empty().id();
+        let z = w.spawn_empty().id();
+        w.entity_mut(a).add_children(&[b0, x, b1]);
+        w.entity_mut(b0).add_children(&[y, z]);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            assert_eq!(a.find_by_path("[0]").unwrap().entity(), b0);
+            assert_eq!(a.find_by_path("[1]").unwrap().entity(), x);
+            assert_eq!(a.find_by_path("[2]").unwrap().entity(), b1);
+            assert_eq!(a.find_by_path("[3]"), None);
+            assert_eq!(a.find_by_path("B[0]").unwrap().entity(), b0);
+            assert_eq!(a.find_by_path("B[1]").unwrap().entity(), b1);
+            assert_eq!(a.find_by_path("B[2]"), None);
+            assert_eq!(a.find_by_path("B/[1]").unwrap().entity(), z);
+            assert_eq!(a.find_by_path("B[0]/[0]").unwrap().entity(), y);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn single_entity() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let entity = w.spawn(T).id();
+
+        w.run_system_once(move |objects: Objects<T>| {
+            assert_eq!(objects.single_entity().unwrap(), entity);
+        })
+        .unwrap();
+
+        w.spawn(T);
+
+        w.run_system_once(|objects: Objects<T>| {
+            assert!(matches!(
+                objects.single_entity(),
+                Err(QuerySingleError::MultipleEntities(_))
+            ));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn object_path() {
+        let path = ObjectPath::new("./../*/B/[1]/B[2]");
+        assert_eq!(
+            path.segments(),
+            &[
+                ObjectPathSegment::Self_,
+                ObjectPathSegment::Parent,
+                ObjectPathSegment::Wildcard,
+                ObjectPathSegment::Name("B".to_owned()),
+                ObjectPathSegment::Index(1),
+                ObjectPathSegment::NameIndex("B".to_owned(), 2),
+            ]
+        );
+        assert_eq!(path.to_string(), "./../*/B/[1]/B[2]");
+        assert_eq!("B/C".parse::<ObjectPath>().unwrap(), ObjectPath::new("B/C"));
+
+        let mut w = World::new();
+        let (a, _, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let path = ObjectPath::new("B/C");
+            assert_eq!(objects.get(a).unwrap().find_by(&path).unwrap().entity(), c);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn for_each_descendant() {
+        #[derive(Component)]
+        struct Marker;
+
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects, mut commands: Commands| {
+            objects
+                .get(b)
+                .unwrap()
+                .for_each_descendant(&mut commands, |entity, commands| {
+                    commands.entity(entity).insert(Marker);
+                });
+        })
+        .unwrap();
+
+        let marked: Vec<Entity> = w
+            .query_filtered::<Entity, With<Marker>>()
+            .iter(&w)
+            .collect();
+        assert_eq!(marked.len(), 3);
+        assert!(!marked.contains(&a));
+        assert!([b, c, d].iter().all(|entity| marked.contains(entity)));
+    }
+
+    #[test]
+    fn instance_ref() {
+        #[derive(Component)]
+        struct T(u32);
+
+        let mut w = World::new();
+        let entity = w.spawn(T(42)).id();
+
+        w.run_system_once(move |world: &World, objects: Objects<T>| {
+            let object = objects.get_ref(world.entity(entity)).unwrap();
+            let instance = object.instance_ref().unwrap();
+            assert_eq!(instance.entity(), entity);
+            assert_eq!(instance.0, 42);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn path_with_separator() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            assert_eq!(c.path_with_separator(" > "), "A > B > C");
+            assert_eq!(a.find_by_path_with_separator("B > C", " > ").unwrap(), c);
+            assert_eq!(a.find_by_path_with_separator("B.C", "."), Some(c));
+            assert_eq!(c.find_by_path_with_separator("..>>D>>..>>C", ">>"), Some(c));
+            assert_eq!(c.find_by_path_with_separator("..", "."), Some(b));
+            assert_eq!(c.find_by_path_with_separator("...D", "."), Some(d));
+            assert_eq!(c.find_by_path_with_separator("...", "."), Some(b));
+            assert_eq!(a.find_by_path_with_separator("..", "."), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn iter_forest_ordered() {
+        fn spawn_forest(w: &mut World) {
+            for _ in 0..3 {
+                w.spawn_empty().with_children(|children| {
+                    children.spawn_empty().with_children(|children| {
+                        children.spawn_empty();
+                        children.spawn_empty();
+                    });
+                    children.spawn_empty();
+                });
+            }
+        }
+
+        fn forest_order(w: &mut World) -> Vec<Entity> {
+            w.run_system_once(|objects: Objects| {
+                let order: Vec<Entity> = objects
+                    .iter_forest_ordered()
+                    .map(|object| object.entity())
+                    .collect();
+                let again: Vec<Entity> = objects
+                    .iter_forest_ordered()
+                    .map(|object| object.entity())
+                    .collect();
+                assert_eq!(order, again);
+                order
+            })
+            .unwrap()
+        }
+
+        let mut w1 = World::new();
+        spawn_forest(&mut w1);
+        let mut w2 = World::new();
+        spawn_forest(&mut w2);
+
+        let order = forest_order(&mut w1);
+        assert_eq!(order.len(), 15);
+        assert_eq!(order, forest_order(&mut w2));
+    }
+
+    #[test]
+    fn find_by_path_duplicate_names() {
+        let mut w = World::new();
+
+        //      A
+        //     / \
+        //   Dup Dup
+        //        |
+        //        X
+
+        let a = w.spawn(Name::new("A")).id();
+        let dup0 = w.spawn(Name::new("Dup")).id();
+        let dup1 = w.spawn(Name::new("Dup")).id();
+        let x = w.spawn(Name::new("X")).id();
+        w.entity_mut(a).add_children(&[dup0, dup1]);
+        w.entity_mut(dup1).add_child(x);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            assert_eq!(a.find_by_path("Dup").unwrap().entity(), dup0);
+            assert_eq!(a.find_by_path("Dup/X").unwrap().entity(), x);
+            assert_eq!(a.find_by_path("Dup/Y"), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn sibling_count() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            assert_eq!(objects.get(a).unwrap().sibling_count(), 0);
+            assert_eq!(objects.get(b).unwrap().sibling_count(), 0);
+            assert_eq!(objects.get(c).unwrap().sibling_count(), 1);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn siblings() {
+        let mut w = World::new();
+        let (a, _, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            assert!(c.siblings().eq([d]));
+            assert!(c.self_and_siblings().eq([c, d]));
+            assert!(d.self_and_siblings().eq([c, d]));
+            assert_eq!(a.siblings().count(), 0);
+            assert!(a.self_and_siblings().eq([a]));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn get_ref_by_entity() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let entity = w.spawn(T).id();
+        let other = w.spawn_empty().id();
+
+        w.run_system_once(move |world: &World, objects: Objects<T>| {
+            let object = objects.get_ref_by_entity(world, entity).unwrap();
+            assert_eq!(object.entity(), entity);
+            assert!(object.contains::<T>());
+            assert!(objects.get_ref_by_entity(world, other).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn next_and_previous_sibling() {
+        let mut w = World::new();
+        let a = w.spawn_empty().id();
+        let b = w.spawn_empty().id();
+        let c = w.spawn_empty().id();
+        let d = w.spawn_empty().id();
+        w.entity_mut(a).add_children(&[b, c, d]);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            assert_eq!(b.next_sibling(), Some(c));
+            assert_eq!(c.next_sibling(), Some(d));
+            assert_eq!(d.next_sibling(), None);
+            assert_eq!(b.previous_sibling(), None);
+            assert_eq!(c.previous_sibling(), Some(b));
+            assert_eq!(d.previous_sibling(), Some(c));
+            assert_eq!(a.next_sibling(), None);
+            assert_eq!(a.previous_sibling(), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn descendants_where() {
+        let mut w = World::new();
+        let a = w.spawn(Name::new("A")).id();
+        let hidden = w.spawn(Name::new("_Hidden")).id();
+        let visible = w.spawn(Name::new("Visible")).id();
+        let item = w.spawn(Name::new("Item")).id();
+        let number = w.spawn(Name::new("1")).id();
+        w.entity_mut(a).add_children(&[hidden, item, number]);
+        w.entity_mut(hidden).add_child(visible);
+
+        w.run_system_once(move |objects: Objects| {
+            let result: Vec<Entity> = objects
+                .get(a)
+                .unwrap()
+                .descendants_where(|object| {
+                    object
+                        .name()
+                        .is_some_and(|name| name.starts_with(char::is_alphabetic))
+                })
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(result, [visible, item]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn child_index() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            assert_eq!(objects.get(a).unwrap().child_index(), None);
+            assert_eq!(objects.get(b).unwrap().child_index(), Some(0));
+            assert_eq!(objects.get(c).unwrap().child_index(), Some(0));
+            assert_eq!(objects.get(d).unwrap().child_index(), Some(1));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn root_entity() {
+        let mut w = World::new();
+        let (a, _, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            assert_eq!(objects.get(c).unwrap().root_entity(), a);
+            assert_eq!(objects.get(a).unwrap().root_entity(), a);
+            assert_eq!(objects.get(c).unwrap().root().entity(), a);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn depth() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            assert_eq!(objects.get(a).unwrap().depth(), 0);
+            assert_eq!(objects.get(b).unwrap().depth(), 1);
+            assert_eq!(objects.get(c).unwrap().depth(), 2);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn iter_labeled() {
+        let mut w = World::new();
+        let named = w.spawn(Name::new("A")).id();
+        let unnamed = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects| {
+            let labels: Vec<(Entity, String)> = objects
+                .iter_labeled()
+                .map(|(object, label)| (object.entity(), label))
+                .collect();
+            assert_eq!(labels.len(), 2);
+            assert!(labels.contains(&(named, "A".to_owned())));
+            assert!(labels.contains(&(unnamed, format!("Entity({unnamed})"))));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn common_ancestor() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        let e = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            let e = objects.get(e).unwrap();
+            assert_eq!(c.common_ancestor(&d), Some(b));
+            assert_eq!(c.common_ancestor(&b), Some(b));
+            assert_eq!(a.common_ancestor(&d), Some(a));
+            assert_eq!(c.common_ancestor(&c), Some(c));
+            assert_eq!(c.common_ancestor(&e), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn is_descendant_of_object() {
+        let mut w = World::new();
+        let (a, b, _, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let d = objects.get(d).unwrap();
+            assert!(d.is_descendant_of_object(&a));
+            assert!(d.is_descendant_of_object(&b));
+            assert!(!a.is_descendant_of_object(&d));
+            assert!(!d.is_descendant_of_object(&d));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn distance_to() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        let e = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            let e = objects.get(e).unwrap();
+            assert_eq!(c.distance_to(&d), Some(2));
+            assert_eq!(c.distance_to(&a), Some(2));
+            assert_eq!(a.distance_to(&c), Some(2));
+            assert_eq!(b.distance_to(&c), Some(1));
+            assert_eq!(c.distance_to(&c), Some(0));
+            assert_eq!(c.distance_to(&e), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn validate_children_kinds() {
+        #[derive(Component)]
+        struct Menu;
+
+        #[derive(Component)]
+        struct MenuItem;
+
+        let mut w = World::new();
+        w.spawn(Menu).with_children(|children| {
+            children.spawn(MenuItem);
+            children.spawn(MenuItem);
+        });
+        let invalid = w
+            .spawn(Menu)
+            .with_children(|children| {
+                children.spawn(MenuItem);
+                children.spawn_empty();
+            })
+            .id();
+
+        w.run_system_once(move |menus: Objects<Menu>, items: Objects<MenuItem>| {
+            assert_eq!(menus.validate_children_kinds(&items), [invalid]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn nth_ancestor() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let c = objects.get(c).unwrap();
+            assert_eq!(c.nth_ancestor(0).unwrap().entity(), b);
+            assert_eq!(c.nth_ancestor(1).unwrap().entity(), a);
+            assert_eq!(c.nth_ancestor(2), None);
+            assert_eq!(c.grandparent().unwrap().entity(), a);
+            assert_eq!(objects.get(b).unwrap().grandparent(), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn find_by_path_trail() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let trail = |path: &str| {
+                objects
+                    .get(a)
+                    .unwrap()
+                    .find_by_path_trail(path)
+                    .map(|trail| {
+                        trail
+                            .iter()
+                            .map(|object| object.entity())
+                            .collect::<Vec<_>>()
+                    })
+            };
+            assert_eq!(trail("B/C"), Some(vec![b, c]));
+            assert_eq!(trail("./B/./C"), Some(vec![b, c]));
+            assert_eq!(trail("B/../B/D"), Some(vec![b, a, b, d]));
+            assert_eq!(trail("*/[1]"), Some(vec![b, d]));
+            assert_eq!(trail("."), Some(vec![]));
+            assert_eq!(trail("B/E"), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn iter_dyn() {
+        #[derive(Component)]
+        struct T(u32);
+
+        let mut w = World::new();
+        w.spawn(T(7));
+        w.spawn_empty();
+        let component_id = w.register_component::<T>();
+
+        w.run_system_once(move |world: &World, objects: Objects| {
+            let values: Vec<Option<u32>> = objects
+                .iter_dyn(world, component_id)
+                .map(|(_, ptr)| ptr.map(|ptr| unsafe { ptr.deref::<T>() }.0))
+                .collect();
+            assert_eq!(values.len(), 2);
+            assert!(values.contains(&Some(7)));
+            assert!(values.contains(&None));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn leaves() {
+        let mut w = World::new();
+        let (a, _, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let leaves: Vec<Entity> = a.leaves().map(|object| object.entity()).collect();
+            assert_eq!(leaves, vec![c, d]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn sort_children_by() {
+        let mut w = World::new();
+        let root = w.spawn(Name::new("Root")).id();
+        let c = w.spawn(Name::new("C")).set_parent(root).id();
+        let a = w.spawn(Name::new("A")).set_parent(root).id();
+        let b = w.spawn(Name::new("B")).set_parent(root).id();
+
+        w.run_system_once(move |mut commands: Commands, objects: Objects| {
+            let root = objects.get(root).unwrap();
+            root.sort_children_by(&mut commands, |a, b| a.name().cmp(&b.name()));
+        })
+        .unwrap();
+
+        let children: Vec<Entity> = w.get::<Children>(root).unwrap().to_vec();
+        assert_eq!(children, vec![a, b, c]);
+    }
+
+    #[test]
+    fn child_and_descendant_count() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let c = objects.get(c).unwrap();
+            assert_eq!(a.child_count(), 1);
+            assert_eq!(a.descendant_count(), 3);
+            assert_eq!(b.child_count(), 2);
+            assert_eq!(b.descendant_count(), 2);
+            assert_eq!(c.child_count(), 0);
+            assert_eq!(c.descendant_count(), 0);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn single_ref_in() {
+        #[derive(Component)]
+        struct T(u32);
+
+        let mut w = World::new();
+        w.spawn(T(7));
+
+        w.run_system_once(|world: &World, objects: Objects<T>| {
+            let object = objects.single_ref_in(world).unwrap();
+            let value: &T = &object;
+            assert_eq!(value.0, 7);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn relationship_to() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        let e = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            let e = objects.get(e).unwrap();
+            assert_eq!(c.relationship_to(&c), Relationship::Same);
+            assert_eq!(a.relationship_to(&c), Relationship::Ancestor);
+            assert_eq!(c.relationship_to(&b), Relationship::Descendant);
+            assert_eq!(c.relationship_to(&d), Relationship::Sibling);
+            assert_eq!(a.relationship_to(&e), Relationship::Unrelated);
+            assert_eq!(b.relationship_to(&e), Relationship::Unrelated);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn descendants_deep_checked() {
+        let mut w = World::new();
+        let a = w.spawn_empty().id();
+        let b = w.spawn_empty().set_parent(a).id();
+        let c = w.spawn_empty().set_parent(b).id();
+        let d = w.spawn_empty().set_parent(b).id();
+        // Malformed: A -> B -> C -> A
+        w.entity_mut(a).set_parent(c);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let descendants: Vec<Entity> = a
+                .descendants_deep_checked()
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(descendants, vec![b, c, d]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn descendants_limited() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        let e = w.spawn_empty().set_parent(c).id();
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let wide = |n| -> Vec<Entity> {
+                a.descendants_wide_limited(n)
+                    .map(|object| object.entity())
+                    .collect()
+            };
+            let deep = |n| -> Vec<Entity> {
+                a.descendants_deep_limited(n)
+                    .map(|object| object.entity())
+                    .collect()
+            };
+            assert!(wide(0).is_empty());
+            assert_eq!(wide(1), vec![b]);
+            assert_eq!(wide(2), vec![b, c, d]);
+            assert_eq!(wide(3), vec![b, c, d, e]);
+            assert!(deep(0).is_empty());
+            assert_eq!(deep(1), vec![b]);
+            assert_eq!(deep(2), vec![b, c, d]);
+            assert_eq!(deep(3), vec![b, c, e, d]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn descendants_with_depth() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let descendants: Vec<(Entity, usize)> = a
+                .descendants_with_depth()
+                .map(|(object, depth)| (object.entity(), depth))
+                .collect();
+            assert_eq!(descendants, vec![(b, 1), (c, 2), (d, 2)]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn find_where() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let d = objects.get(d).unwrap();
+            let is_named = |name: &'static str| move |object: &Object| object.name() == Some(name);
+            assert_eq!(d.find_ancestor_where(is_named("A")), Some(a));
+            assert_eq!(d.find_ancestor_where(is_named("D")), None);
+            assert_eq!(
+                a.find_descendant_where_wide(is_named("C"))
+                    .unwrap()
+                    .entity(),
+                c
+            );
+            assert_eq!(
+                a.find_descendant_where_deep(is_named("B"))
+                    .unwrap()
+                    .entity(),
+                b
+            );
+            assert_eq!(a.find_descendant_where_deep(is_named("A")), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn ancestors_until() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+        let e = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects| {
+            let c = objects.get(c).unwrap();
+            let until = |stop| -> Vec<Entity> {
+                c.ancestors_until(stop)
+                    .map(|object| object.entity())
+                    .collect()
+            };
+            assert_eq!(until(b), vec![]);
+            assert_eq!(until(a), vec![b]);
+            assert_eq!(until(e), vec![b, a]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn objects_mut() {
+        #[derive(Component)]
+        struct Depth {
+            value: usize,
+        }
+
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        for entity in [a, b, c, d] {
+            w.entity_mut(entity).insert(Depth { value: 0 });
+        }
+
+        w.run_system_once(|mut objects: ObjectsMut<Depth>| {
+            for mut object in objects.iter_mut() {
+                object.value = object.depth();
+            }
+        })
+        .unwrap();
+
+        w.run_system_once(move |mut objects: ObjectsMut<Depth>| {
+            let mut d = objects.get_mut(d).unwrap();
+            assert_eq!(d.parent().unwrap().entity(), b);
+            d.value += 10;
+        })
+        .unwrap();
+
+        assert_eq!(w.get::<Depth>(a).unwrap().value, 0);
+        assert_eq!(w.get::<Depth>(b).unwrap().value, 1);
+        assert_eq!(w.get::<Depth>(c).unwrap().value, 2);
+        assert_eq!(w.get::<Depth>(d).unwrap().value, 12);
+    }
+
+    #[test]
+    fn get_mut_ref() {
+        #[derive(Component)]
+        struct Health {
+            value: u32,
+        }
+
+        let mut w = World::new();
+        let (_, b, c, _) = spawn_sample_tree(&mut w);
+        w.entity_mut(c).insert(Health { value: 10 });
+
+        w.run_system_once(move |objects: Objects, mut query: Query<ObjectEntityMut>| {
+            let object = objects.get_mut_ref(query.get_mut(c).unwrap());
+            let mut object = object.unwrap();
+            assert_eq!(object.parent().unwrap().entity(), b);
+            assert_eq!(object.name(), Some("C"));
+            assert!(!object.contains::<Name>());
+            object.get_mut::<Health>().unwrap().value -= 3;
+        })
+        .unwrap();
+
+        assert_eq!(w.get::<Health>(c).unwrap().value, 7);
+    }
+
+    #[test]
+    fn reparent() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |mut commands: Commands, objects: Objects| {
+            let c = objects.get(c).unwrap();
+            c.reparent(&mut commands, a);
+        })
+        .unwrap();
+
+        w.run_system_once(move |objects: Objects| {
+            let c = objects.get(c).unwrap();
+            assert_eq!(c.parent().unwrap().entity(), a);
+            assert!(!objects.get(b).unwrap().children().any(|child| child == c));
+        })
+        .unwrap();
+
+        w.run_system_once(move |mut commands: Commands, objects: Objects| {
+            let c = objects.get(c).unwrap();
+            c.clear_parent(&mut commands);
+        })
+        .unwrap();
+
+        w.run_system_once(move |objects: Objects| {
+            let c = objects.get(c).unwrap();
+            assert!(c.is_root());
+            assert!(!objects.get(a).unwrap().children().any(|child| child == c));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn get_many() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let [x, y] = objects.get_many([a, c]).unwrap();
+            assert_eq!(x.entity(), a);
+            assert_eq!(y.entity(), c);
+            assert!(x.is_ancestor_of(y.entity()));
+        })
+        .unwrap();
+
+        let e = w.spawn_empty().id();
+        w.despawn(e);
+        w.run_system_once(move |objects: Objects| {
+            assert!(objects.get_many([b, e]).is_err());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn iter_many() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let a = w.spawn(T).id();
+        let b = w.spawn_empty().id();
+        let c = w.spawn(T).id();
+
+        w.run_system_once(move |objects: Objects<T>| {
+            let selected: Vec<Entity> = objects
+                .iter_many([c, b, a])
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(selected, vec![c, a]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn len() {
+        #[derive(Component)]
+        struct T;
+
+        #[derive(Component)]
+        struct U;
+
+        let mut w = World::new();
+        w.spawn(T);
+        w.spawn(T);
+        w.spawn_empty();
+
+        w.run_system_once(|t: Objects<T>, u: Objects<U>| {
+            assert_eq!(t.len(), 2);
+            assert!(!t.is_empty());
+            assert_eq!(u.len(), 0);
+            assert!(u.is_empty());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn par_iter() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        bevy::tasks::ComputeTaskPool::get_or_init(bevy::tasks::TaskPool::default);
+
+        let mut w = World::new();
+        spawn_sample_tree(&mut w);
+
+        w.run_system_once(|objects: Objects| {
+            let count = AtomicUsize::new(0);
+            objects.par_iter().for_each(|object| {
+                if object.name().is_some() {
+                    count.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+            assert_eq!(count.into_inner(), 4);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn leaf_objects() {
+        let mut w = World::new();
+        let (_, _, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: LeafObjects| {
+            let leaves: Vec<Entity> = objects.iter().map(|object| object.entity()).collect();
+            assert_eq!(leaves.len(), 2);
+            assert!(leaves.contains(&c));
+            assert!(leaves.contains(&d));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn find() {
+        let mut w = World::new();
+        let (_, _, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let object = objects.find(|object| object.name() == Some("C"));
+            assert_eq!(object.unwrap().entity(), c);
+            assert!(objects.find(|object| object.name() == Some("E")).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn component() {
+        #[derive(Component)]
+        struct Health(u32);
+
+        let mut w = World::new();
+        let a = w.spawn(Health(10)).id();
+        let b = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects, query: Query<&Health>| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            assert_eq!(objects.component(&a, &query).unwrap().0, 10);
+            assert!(objects.component(&b, &query).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn object_ref_get_ref() {
+        #[derive(Component)]
+        struct T(u32);
+
+        let mut w = World::new();
+        let entity = w.spawn(T(1)).id();
+
+        w.run_system_once(move |world: &World, objects: Objects| {
+            let object = objects.get_ref_by_entity(world, entity).unwrap();
+            let t = object.get_ref::<T>().unwrap();
+            assert_eq!(t.0, 1);
+            assert!(t.is_added());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn object_hash() {
+        use std::collections::HashSet;
+
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |world: &World, objects: Objects| {
+            let mut visited = HashSet::new();
+            for entity in [a, b, c, d, a, c] {
+                visited.insert(objects.get(entity).unwrap());
+            }
+            assert_eq!(visited.len(), 4);
+            assert!(visited.contains(&objects.get(d).unwrap()));
+
+            let mut visited = HashSet::new();
+            for entity in [a, b, a] {
+                visited.insert(objects.get_ref_by_entity(world, entity).unwrap());
+            }
+            assert_eq!(visited.len(), 2);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn object_ord() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let mut sorted: Vec<Object> = objects.iter_many([d, b, c, a]).collect();
+            sorted.sort();
+            let mut expected = vec![a, b, c, d];
+            expected.sort();
+            let sorted: Vec<Entity> = sorted.into_iter().map(|object| object.entity()).collect();
+            assert_eq!(sorted, expected);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn try_cast_into() {
+        #[derive(Component)]
+        struct T;
+
+        #[derive(Component)]
+        struct U;
+
+        let mut w = World::new();
+        let entity = w.spawn(T).id();
+
+        w.run_system_once(move |world: &World, objects: Objects| {
+            let object = objects.get(entity).unwrap();
+            let t: Object<T> = object.try_cast_into::<T>(world).unwrap();
+            assert_eq!(t.entity(), entity);
+            assert!(object.try_cast_into::<U>(world).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn from_base_of_kind() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = Or<(With<Apple>, With<Orange>)>;
+        }
+
+        let mut w = World::new();
+        let apple = w.spawn(Apple).id();
+        let orange = w.spawn(Orange).id();
+        let rock = w.spawn_empty().id();
+
+        w.run_system_once(move |world: &World, objects: Objects| {
+            let fruit =
+                |entity| Object::<Fruit>::from_base_of_kind(world, objects.get(entity).unwrap());
+            assert_eq!(fruit(apple).unwrap().entity(), apple);
+            assert_eq!(fruit(orange).unwrap().entity(), orange);
+            assert!(fruit(rock).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn object_get_component() {
+        #[derive(Component)]
+        struct Health(u32);
+
+        let mut w = World::new();
+        let a = w.spawn(Health(5)).id();
+        let b = w.spawn_empty().id();
+
+        w.run_system_once(move |world: &World, objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            assert_eq!(a.entity_ref(world).id(), a.entity());
+            assert_eq!(a.get_component::<Health>(world).unwrap().0, 5);
+            assert!(b.get_component::<Health>(world).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn object_path_ref_serde() {
+        let mut w = World::new();
+        let (a, _, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let c = objects.get(c).unwrap();
+
+            let json = serde_json::to_string(&ObjectPathRef::new(&c)).unwrap();
+            assert_eq!(json, "\"A/B/C\"");
+            let path: ObjectPathRef = serde_json::from_str(&json).unwrap();
+            assert_eq!(path.resolve(&a), Some(c));
+
+            let mut json = Vec::new();
+            serialize_as_path(&c, &mut serde_json::Serializer::new(&mut json)).unwrap();
+            let mut deserializer = serde_json::Deserializer::from_slice(&json);
+            let path = deserialize_from_path(&mut deserializer).unwrap();
+            assert_eq!(path.resolve(&a), Some(c));
+            assert_eq!(path.resolve(&c), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn fmt_tree() {
+        struct Tree<'w, 's, 'a>(Object<'w, 's, 'a>);
+
+        impl fmt::Display for Tree<'_, '_, '_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt_tree(f)
+            }
+        }
+
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        let e = w.spawn(Name::new("E")).set_parent(c).id();
+
+        w.run_system_once(move |objects: Objects| {
+            let [a, b, c, d, e] = objects.get_many([a, b, c, d, e]).unwrap();
+            let expected = format!("{a:?}\n  {b:?}\n    {c:?}\n      {e:?}\n    {d:?}\n");
+            assert_eq!(Tree(a).to_string(), expected);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn object_debug_alternate() {
+        let mut w = World::new();
+        let (_, _, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let c = objects.get(c).unwrap();
+            assert!(!format!("{c:?}").contains("A/B/C"));
+            assert!(format!("{c:#?}").contains("\"A/B/C\""));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn root_of_kind() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        w.entity_mut(b).insert(T);
+        w.entity_mut(c).insert(T);
+
+        w.run_system_once(move |objects: Objects, t: Objects<T>| {
+            let c = objects.get(c).unwrap();
+            let d = objects.get(d).unwrap();
+            let a = objects.get(a).unwrap();
+            assert_eq!(c.root_of_kind(&t).unwrap().entity(), b);
+            assert_eq!(d.root_of_kind(&t).unwrap().entity(), b);
+            assert!(a.root_of_kind(&t).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn subtree_entities() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            assert_eq!(a.subtree_entities(), vec![b, c, d]);
+            assert_eq!(a.self_and_subtree_entities(), vec![a.entity(), b, c, d]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn despawn() {
+        let mut w = World::new();
+        let root = w.spawn_empty().id();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        w.entity_mut(a).set_parent(root);
+
+        w.run_system_once(move |mut commands: Commands, objects: Objects| {
+            objects.get(b).unwrap().despawn(&mut commands);
+        })
+        .unwrap();
+
+        for entity in [b, c, d] {
+            assert!(w.get_entity(entity).is_err());
+        }
+        assert!(w.get::<Children>(a).is_none());
+        assert!(w.get_entity(root).is_ok());
+    }
+
+    #[test]
+    fn ancestors_of_kind_while() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+        let e = w.spawn(T).set_parent(c).id();
+        w.entity_mut(c).insert(T);
+        w.entity_mut(a).insert(T);
+
+        w.run_system_once(move |objects: Objects, t: Objects<T>| {
+            let e = objects.get(e).unwrap();
+            let chain: Vec<Entity> = e
+                .ancestors_of_kind_while(&t)
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(chain, vec![c]);
+            let all: Vec<Entity> = e
+                .ancestors_of_kind(&t)
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(all, vec![c, a]);
+            assert!(objects
+                .get(b)
+                .unwrap()
+                .ancestors_of_kind_while(&t)
+                .next()
+                .is_some());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn is_sibling_of() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        let e = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects| {
+            let [a, b, c, d, e] = objects.get_many([a, b, c, d, e]).unwrap();
+            assert!(c.is_sibling_of(&d));
+            assert!(d.is_sibling_of(&c));
+            assert!(!c.is_sibling_of(&c));
+            assert!(!c.is_sibling_of(&b));
+            assert!(!a.is_sibling_of(&e));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn descendants_by_level() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let levels: Vec<Vec<Entity>> = a
+                .descendants_by_level()
+                .into_iter()
+                .map(|level| level.into_iter().map(|object| object.entity()).collect())
+                .collect();
+            assert_eq!(levels, vec![vec![b], vec![c, d]]);
+            assert!(objects.get(c).unwrap().descendants_by_level().is_empty());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn walk() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let events = std::cell::RefCell::new(Vec::new());
+            a.walk(
+                |object, depth| events.borrow_mut().push(("enter", object.entity(), depth)),
+                |object, depth| events.borrow_mut().push(("exit", object.entity(), depth)),
+            );
+            assert_eq!(
+                events.into_inner(),
+                vec![
+                    ("enter", a.entity(), 0),
+                    ("enter", b, 1),
+                    ("enter", c, 2),
+                    ("exit", c, 2),
+                    ("enter", d, 2),
+                    ("exit", d, 2),
+                    ("exit", b, 1),
+                    ("exit", a.entity(), 0),
+                ]
+            );
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn fold_descendants() {
+        let mut w = World::new();
+        let (a, _, _, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let names = a.fold_descendants(String::new(), |mut names, object| {
+                names.push_str(object.name().unwrap());
+                names
+            });
+            assert_eq!(names, "ABCD");
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn single() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let entity = w.spawn(T).id();
+
+        w.run_system_once(move |objects: Objects<T>| {
+            assert_eq!(objects.single().entity(), entity);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "found many")]
+    fn single_panics() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        w.spawn(T);
+        w.spawn(T);
+
+        w.run_system_once(|objects: Objects<T>| {
+            objects.single();
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn any_all() {
+        let mut w = World::new();
+        spawn_sample_tree(&mut w);
+
+        w.run_system_once(|objects: Objects| {
+            assert!(objects.any(|object| object.is_root()));
+            assert!(!objects.all(|object| object.is_root()));
+            assert!(objects.all(|object| object.name().is_some()));
+            assert!(!objects.any(|object| object.name() == Some("E")));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn reborrow() {
+        struct Visitor<'w, 's, 'a> {
+            object: Object<'w, 's, 'a>,
+            visited: &'a mut Vec<Entity>,
+        }
+
+        let mut w = World::new();
+        let (a, b, _, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let mut visited = Vec::new();
+            let visitor = Visitor {
+                object: a.reborrow(),
+                visited: &mut visited,
+            };
+            visitor
+                .visited
+                .extend(visitor.object.children().map(|child| child.entity()));
+            assert_eq!(visited, vec![b]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn iter_descendants_of() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        w.entity_mut(b).insert(T);
+        w.entity_mut(d).insert(T);
+        let e = w.spawn_empty().id();
+        w.despawn(e);
+
+        w.run_system_once(move |objects: Objects<T>| {
+            let descendants: Vec<Entity> = objects
+                .iter_descendants_of(a)
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(descendants, vec![b, d]);
+            assert_eq!(objects.iter_descendants_of(c).count(), 0);
+            assert_eq!(objects.iter_descendants_of(e).count(), 0);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn name_or() {
+        #[derive(Component)]
+        struct T;
+
+        fn name_of(object: &impl ObjectName) -> Option<&str> {
+            object.name()
+        }
+
+        let mut w = World::new();
+        let a = w.spawn((T, Name::new("A"))).id();
+        let b = w.spawn(T).id();
+
+        w.run_system_once(move |objects: Objects<T>| {
+            let a = objects.get(a).unwrap();
+            let b = objects.get(b).unwrap();
+            assert_eq!(name_of(&a), Some("A"));
+            assert_eq!(a.name_or("Unnamed"), "A");
+            assert_eq!(b.name_or("Unnamed"), "Unnamed");
+            assert_eq!(a.name_or_kind(), "A");
+            assert_eq!(b.name_or_kind(), T::debug_name());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn has_name() {
+        let mut w = World::new();
+        let a = w.spawn(Name::new("A")).id();
+        let b = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects| {
+            assert!(objects.get(a).unwrap().has_name());
+            assert!(!objects.get(b).unwrap().has_name());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn set_name() {
+        let mut w = World::new();
+        let a = w.spawn(Name::new("A")).id();
+        let b = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects, mut commands: Commands| {
+            objects.get(a).unwrap().set_name(&mut commands, "X");
+            objects.get(b).unwrap().set_name(&mut commands, "Y");
+        })
+        .unwrap();
+
+        w.run_system_once(move |objects: Objects| {
+            assert_eq!(objects.get(a).unwrap().name(), Some("X"));
+            assert_eq!(objects.get(b).unwrap().name(), Some("Y"));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "has no")]
+    fn object_ref_deref_missing() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let a = w.spawn_empty().id();
+
+        w.run_system_once(move |objects: Objects, query: Query<EntityRef>| {
+            let object = objects.get_ref(query.get(a).unwrap()).unwrap();
+            let object: ObjectRef<T> = unsafe { object.cast_into_unchecked() };
+            let _: &T = &object;
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn is_ancestor_or_self_of() {
+        let mut w = World::new();
+        let (a, b, c, _) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a_obj = objects.get(a).unwrap();
+            let c_obj = objects.get(c).unwrap();
+            assert!(!a_obj.is_ancestor_of(a));
+            assert!(a_obj.is_ancestor_or_self_of(a));
+            assert!(a_obj.is_ancestor_or_self_of(c));
+            assert!(!c_obj.is_ancestor_or_self_of(b));
+            assert!(!c_obj.is_descendant_of(c));
+            assert!(c_obj.is_descendant_or_self_of(c));
+            assert!(c_obj.is_descendant_or_self_of(a));
+            assert!(!a_obj.is_descendant_or_self_of(b));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn find_by_path_indexed() {
+        let mut app = App::new();
+        app.add_plugins(ObjectNameIndexPlugin);
+        let (a, b, c, d) = spawn_sample_tree(app.world_mut());
+        app.update();
+
+        let index = app.world().resource::<ObjectNameIndex>();
+        assert_eq!(index.get(a, "B"), Some(b));
+        assert_eq!(index.get(b, "D"), Some(d));
+
+        let find = move |app: &mut App, entity: Entity, path: &'static str| {
+            app.world_mut()
+                .run_system_once(move |objects: Objects, index: Res<ObjectNameIndex>| {
+                    objects
+                        .find_by_path_indexed(&index, entity, path)
+                        .map(|object| object.entity())
+                })
+                .unwrap()
+        };
+
+        assert_eq!(find(&mut app, a, "B/C"), Some(c));
+        assert_eq!(find(&mut app, c, "../D"), Some(d));
+        assert_eq!(find(&mut app, a, "B/*"), Some(c));
+        assert_eq!(find(&mut app, a, "B/E"), None);
+
+        // Stale index falls back to a linear search
+        app.world_mut().entity_mut(c).insert(Name::new("E"));
+        assert_eq!(find(&mut app, a, "B/E"), Some(c));
+        assert_eq!(find(&mut app, a, "B/C"), None);
+
+        app.update();
+        let index = app.world().resource::<ObjectNameIndex>();
+        assert_eq!(index.get(b, "E"), Some(c));
+        assert_eq!(index.get(b, "C"), None);
+
+        // Reparented and despawned objects are evicted from their previous parent
+        app.world_mut().entity_mut(d).set_parent(a);
+        app.update();
+        let index = app.world().resource::<ObjectNameIndex>();
+        assert_eq!(index.get(b, "D"), None);
+        assert_eq!(index.get(a, "D"), Some(d));
+        assert_eq!(index.get(b, "E"), Some(c));
+
+        app.world_mut().entity_mut(b).despawn_recursive();
+        app.update();
+        let index = app.world().resource::<ObjectNameIndex>();
+        assert_eq!(index.get(a, "B"), None);
+        assert_eq!(index.get(b, "E"), None);
+        assert_eq!(index.get(a, "D"), Some(d));
+
+        app.world_mut().entity_mut(d).remove::<Name>();
+        app.update();
+        assert!(app.world().resource::<ObjectNameIndex>().is_empty());
+    }
+
+    #[test]
+    fn descendants_into() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let mut buffer = vec![Entity::PLACEHOLDER];
+            objects.get(a).unwrap().descendants_into(&mut buffer);
+            assert_eq!(buffer, [Entity::PLACEHOLDER, b, c, d]);
+
+            buffer.clear();
+            objects.get(c).unwrap().descendants_into(&mut buffer);
+            assert!(buffer.is_empty());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn objects_hierarchy_query() {
+        let mut w = World::new();
+        let (_, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let hierarchy = objects.hierarchy();
+            assert_eq!(hierarchy.parent(c), Some(b));
+            assert_eq!(hierarchy.children(b).collect::<Vec<_>>(), [c, d]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn get_any() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let a = w.spawn(Name::new("A")).id();
+        let b = w.spawn_empty().id();
+        w.despawn(b);
+
+        w.run_system_once(move |objects: Objects<T>| {
+            assert!(objects.get(a).is_err());
+            assert_eq!(objects.get_any(a).unwrap().name(), Some("A"));
+            assert!(objects.get_any(b).is_none());
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn descendants_deep_postorder() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let order: Vec<Entity> = objects
+                .get(a)
+                .unwrap()
+                .descendants_deep_postorder()
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(order, [c, d, b]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn children_sorted_by_name() {
+        let mut w = World::new();
+        let a = w.spawn_empty().id();
+        let z = w.spawn(Name::new("Z")).set_parent(a).id();
+        let x = w.spawn(Name::new("X")).set_parent(a).id();
+        let u = w.spawn_empty().set_parent(a).id();
+
+        w.run_system_once(move |objects: Objects| {
+            let object = objects.get(a).unwrap();
+            let sorted: Vec<Entity> = object
+                .children_sorted_by_name()
+                .iter()
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(sorted, [u, x, z]);
+
+            let sorted: Vec<Entity> = object
+                .children_sorted_by(|a, b| b.name().cmp(&a.name()))
+                .iter()
+                .map(|object| object.entity())
+                .collect();
+            assert_eq!(sorted, [z, x, u]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn iter_combinations() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let a = w.spawn(T).id();
+        let b = w.spawn(T).id();
+        let c = w.spawn(T).id();
+        w.spawn_empty();
+
+        w.run_system_once(move |objects: Objects<T>| {
+            let mut pairs: Vec<[Entity; 2]> = objects
+                .iter_combinations::<2>()
+                .map(|[x, y]| {
+                    let mut pair = [x.entity(), y.entity()];
+                    pair.sort();
+                    pair
+                })
+                .collect();
+            pairs.sort();
+            assert_eq!(pairs, [[a, b], [a, c], [b, c]]);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn contains_descendant() {
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a_obj = objects.get(a).unwrap();
+            let b_obj = objects.get(b).unwrap();
+            let c_obj = objects.get(c).unwrap();
+            assert!(a_obj.contains_descendant(c));
+            assert!(b_obj.contains_descendant(d));
+            assert!(!a_obj.contains_descendant(a));
+            assert!(!c_obj.contains_descendant(b));
+            assert!(c_obj.is_descendant_of(a));
+            assert!(!b_obj.is_descendant_of(c));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn as_instance_of() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let a = w.spawn(T).id();
+        let b = w.spawn_empty().id();
+
+        let mut state = SystemState::<Objects>::new(&mut w);
+        let objects = state.get(&w);
+        let instance = objects.get(a).unwrap().as_instance_of::<T>(&w);
+        assert_eq!(instance.map(|instance| instance.entity()), Some(a));
+        assert!(objects.get(b).unwrap().as_instance_of::<T>(&w).is_none());
+    }
+
+    #[test]
+    fn object_hierarchy_changed() {
+        let mut app = App::new();
+        app.add_plugins(ObjectHierarchyPlugin);
+        let (a, b, c, _) = spawn_sample_tree(app.world_mut());
+        let e = app.world_mut().spawn_empty().id();
+
+        let mut cursor = app
+            .world()
+            .resource::<Events<ObjectHierarchyChanged>>()
+            .get_cursor_current();
+        let mut roots = |app: &App| {
+            let events = app.world().resource::<Events<ObjectHierarchyChanged>>();
+            let mut roots: Vec<Entity> = cursor.read(events).map(|event| event.root).collect();
+            roots.sort();
+            roots.dedup();
+            roots
+        };
+        assert_eq!(roots(&app), []);
+
+        app.world_mut().entity_mut(c).insert(Name::new("X"));
+        assert_eq!(roots(&app), [a]);
+
+        app.world_mut().entity_mut(b).set_parent(e);
+        assert_eq!(roots(&app), [a, e]);
+
+        app.world_mut().entity_mut(b).remove_parent();
+        assert_eq!(roots(&app), [b, e]);
+
+        // Reparenting to an object with children modifies the hierarchy in place
+        let f = app.world_mut().spawn_empty().id();
+        let g = app.world_mut().spawn_empty().set_parent(f).id();
+        app.update();
+        roots(&app);
+        app.world_mut().entity_mut(g).set_parent(b);
+        assert_eq!(roots(&app), [f]);
+        app.update();
+        assert_eq!(roots(&app), [b]);
+    }
+
+    #[test]
+    fn get_single_in_subtree() {
+        #[derive(Component)]
+        struct T;
+
+        let mut w = World::new();
+        let (a, b, c, d) = spawn_sample_tree(&mut w);
+        w.entity_mut(c).insert(T);
+        w.entity_mut(d).insert(T);
+        let e = w.spawn(T).id();
+        let f = w.spawn(T).set_parent(e).id();
+
+        w.run_system_once(move |objects: Objects<T>| {
+            assert!(objects.get_single().is_err());
+            assert_eq!(objects.get_single_in_subtree(c).unwrap().entity(), c);
+            assert!(matches!(
+                objects.get_single_in_subtree(b),
+                Err(QuerySingleError::MultipleEntities(_))
+            ));
+            assert!(matches!(
+                objects.get_single_in_subtree(e),
+                Err(QuerySingleError::MultipleEntities(_))
+            ));
+            assert_eq!(objects.get_single_in_subtree(f).unwrap().entity(), f);
+            assert!(matches!(
+                objects.get_single_in_subtree(a),
+                Err(QuerySingleError::MultipleEntities(_))
+            ));
+        })
+        .unwrap();
+
+        w.entity_mut(d).remove::<T>();
+        w.run_system_once(move |objects: Objects<T>| {
+            assert_eq!(objects.get_single_in_subtree(a).unwrap().entity(), c);
+            assert!(matches!(
+                objects.get_single_in_subtree(d),
+                Err(QuerySingleError::NoEntities(_))
+            ));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn map_children() {
+        let mut w = World::new();
+        let (a, ..) = spawn_sample_tree(&mut w);
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            assert_eq!(a.map_children(|object| object.path()), ["A/B"]);
+            assert_eq!(
+                a.map_descendants(|object| object.name().unwrap().to_owned()),
+                ["B", "C", "D"]
+            );
+        })
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "path separator must not contain")]
+    fn path_separator_wildcard() {
+        ObjectPath::with_separator("A*B", "*");
+    }
+
+    #[test]
+    fn path_to_duplicate_names() {
+        let mut w = World::new();
+        let a = w.spawn(Name::new("A")).id();
+        let x0 = w.spawn(Name::new("X")).set_parent(a).id();
+        let x1 = w.spawn(Name::new("X")).set_parent(a).id();
+        let y = w.spawn(Name::new("Y")).set_parent(x1).id();
+
+        w.run_system_once(move |objects: Objects| {
+            let a = objects.get(a).unwrap();
+            let x0 = objects.get(x0).unwrap();
+            let y = objects.get(y).unwrap();
+            let path = x0.path_to(&y).unwrap();
+            assert_eq!(path, "../X[1]/Y");
+            assert_eq!(x0.find_by_path(path), Some(y));
+            assert_eq!(a.path_to(&x0).unwrap(), "X[0]");
+
+            let path = y.path_relative_to(&a).unwrap();
+            assert_eq!(path, "X[1]/Y");
+            assert_eq!(a.find_by_path(path), Some(y));
+            assert_eq!(x0.path_relative_to(&a).unwrap(), "X[0]");
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn iter_root_ref() {
+        let mut w = World::new();
+        let (a, ..) = spawn_sample_tree(&mut w);
+        let e = w.spawn_empty().id();
+
+        let mut state = SystemState::<Objects>::new(&mut w);
+        let objects = state.get(&w);
+        let mut roots: Vec<Entity> = objects
+            .iter_root_ref(&w)
+            .map(|object| object.entity())
+            .collect();
+        roots.sort();
+        assert_eq!(roots, [a, e]);
+    }
+
+    #[test]
+    fn object_path_ref_duplicates() {
+        let mut w = World::new();
+        let r = w.spawn(Name::new("R")).id();
+        let x0 = w.spawn(Name::new("X")).set_parent(r).id();
+        let x1 = w.spawn(Name::new("X")).set_parent(r).id();
+        let y = w.spawn(Name::new("Y")).set_parent(x1).id();
+        let u0 = w.spawn_empty().set_parent(r).id();
+        let u1 = w.spawn_empty().set_parent(r).id();
+        let z = w.spawn(Name::new("Z")).set_parent(u1).id();
+
+        w.run_system_once(move |objects: Objects| {
+            let r = objects.get(r).unwrap();
+            for (entity, expected) in [
+                (x0, "R/X[0]"),
+                (y, "R/X[1]/Y"),
+                (u0, "R/[2]"),
+                (z, "R/[3]/Z"),
+            ] {
+                let object = objects.get(entity).unwrap();
+                let path = ObjectPathRef::new(&object);
+                assert_eq!(path.to_string(), expected);
+                assert_eq!(path.resolve(&r), Some(object));
+
+                #[cfg(feature = "serde")]
+                {
+                    let json = serde_json::to_string(&path).unwrap();
+                    let path: ObjectPathRef = serde_json::from_str(&json).unwrap();
+                    assert_eq!(path.resolve(&r), Some(object));
+                }
+            }
+        })
+        .unwrap();
+    }
 }
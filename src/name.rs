@@ -1,6 +1,7 @@
+use bevy_ecs::prelude::*;
 use moonshine_kind::prelude::*;
 
-use crate::{Object, ObjectInstance, ObjectRef};
+use crate::{Object, ObjectInstance, ObjectMut, ObjectRef, ObjectRefMut};
 
 pub trait ObjectName {
     /// Returns the [`Name`] of this object.
@@ -25,6 +26,20 @@ pub trait ObjectName {
     ///
     /// [`Name`]: https://docs.rs/bevy/latest/bevy/core/struct.Name.html
     fn name(&self) -> Option<&str>;
+
+    /// Returns `true` if this object has a [`Name`].
+    ///
+    /// [`Name`]: https://docs.rs/bevy/latest/bevy/core/struct.Name.html
+    fn has_name(&self) -> bool {
+        self.name().is_some()
+    }
+
+    /// Returns the [`Name`] of this object, or `default` if it has none.
+    ///
+    /// [`Name`]: https://docs.rs/bevy/latest/bevy/core/struct.Name.html
+    fn name_or<'a>(&'a self, default: &'a str) -> &'a str {
+        self.name().unwrap_or(default)
+    }
 }
 
 impl<T: Kind> ObjectName for Object<'_, '_, '_, T> {
@@ -38,3 +53,15 @@ impl<T: Kind> ObjectName for ObjectRef<'_, '_, '_, T> {
         self.1.name()
     }
 }
+
+impl<T: Component> ObjectName for ObjectMut<'_, '_, '_, T> {
+    fn name(&self) -> Option<&str> {
+        self.1.name()
+    }
+}
+
+impl<T: Kind> ObjectName for ObjectRefMut<'_, '_, '_, T> {
+    fn name(&self) -> Option<&str> {
+        self.1.name()
+    }
+}
@@ -0,0 +1,269 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use moonshine_kind::{Any, Kind};
+
+use crate::{hierarchy::indexed_segment, ObjectHierarchy};
+
+/// A pre-parsed path to an object within a hierarchy.
+///
+/// See [`find_by_path`](crate::ObjectHierarchy::find_by_path) for details on the path syntax.
+///
+/// Resolving a path string requires it to be parsed each time. If the same path is resolved
+/// frequently, prefer to parse it once into an [`ObjectPath`] and resolve it using
+/// [`find_by`](crate::ObjectHierarchy::find_by) instead.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_object::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Bird;
+///
+/// let mut app = App::new();
+/// // ...
+/// app.add_systems(Update, find_wings);
+///
+/// fn find_wings(birds: Objects<Bird>, mut path: Local<Option<ObjectPath>>) {
+///     let path = path.get_or_insert_with(|| ObjectPath::new("Body/Wings"));
+///     for bird in birds.iter() {
+///         if let Some(wings) = bird.find_by(path) {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ObjectPath(Vec<ObjectPathSegment>);
+
+impl ObjectPath {
+    /// Parses the given path string into an [`ObjectPath`].
+    pub fn new(path: &str) -> Self {
+        Self::with_separator(path, "/")
+    }
+
+    /// Parses the given path string into an [`ObjectPath`], using the given separator instead of a slash.
+    ///
+    /// The separator may overlap with `.` and `..` segments, such as when using `.` as a separator.
+    /// In that case, `.` and `..` are matched before the separator, so `..` is always the parent
+    /// and `...` is the parent followed by an empty segment.
+    ///
+    /// # Panics
+    /// Panics if `separator` is empty, or if it contains `*`, `[` or `]`, since these would make paths ambiguous.
+    pub fn with_separator(path: &str, separator: &str) -> Self {
+        assert!(!separator.is_empty(), "path separator must not be empty");
+        assert!(
+            !separator.contains(['*', '[', ']']),
+            "path separator must not contain `*`, `[` or `]`"
+        );
+        let mut segments = Vec::new();
+        let mut rest = Some(path);
+        while let Some(path) = rest {
+            let (segment, next) = split_segment(path, separator);
+            segments.push(ObjectPathSegment::new(segment));
+            rest = next;
+        }
+        Self(segments)
+    }
+
+    /// Returns the segments of this path.
+    pub fn segments(&self) -> &[ObjectPathSegment] {
+        &self.0
+    }
+}
+
+impl From<&str> for ObjectPath {
+    fn from(path: &str) -> Self {
+        Self::new(path)
+    }
+}
+
+impl FromStr for ObjectPath {
+    type Err = Infallible;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(path))
+    }
+}
+
+impl fmt::Display for ObjectPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str("/")?;
+            }
+            segment.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A reference to an object which is stored as its path from its root.
+///
+/// Unlike an [`Entity`](bevy_ecs::entity::Entity), a path remains valid across save and load,
+/// as long as the hierarchy, names and order of the objects are preserved.
+///
+/// Unlike [`path`](ObjectHierarchy::path), each segment identifies exactly one object:
+/// Objects which share their name with a sibling are written as `Name[n]`, and unnamed objects as `[n]`.
+///
+/// With the `serde` feature enabled, this type is serialized as its path string.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectPathRef(ObjectPath);
+
+impl ObjectPathRef {
+    /// Creates a new [`ObjectPathRef`] from the path of the given object.
+    pub fn new<T: Kind>(object: &impl ObjectHierarchy<T>) -> Self {
+        let mut segments: Vec<ObjectPathSegment> = object
+            .self_and_ancestors()
+            .map(|object| indexed_segment(&object))
+            .collect();
+        segments.reverse();
+        Self(ObjectPath(segments))
+    }
+
+    /// Returns the path of this reference, starting from the root.
+    pub fn path(&self) -> &ObjectPath {
+        &self.0
+    }
+
+    /// Resolves this reference within the hierarchy of the given `root` object.
+    ///
+    /// The first segment of the path must match the name of `root`.
+    /// Returns `None` if it does not, or if no object exists at the given path.
+    pub fn resolve<T: Kind, H: ObjectHierarchy<T>>(&self, root: &H) -> Option<H::Rebind<Any>> {
+        let (head, tail) = self.0.segments().split_first()?;
+        match head {
+            ObjectPathSegment::Name(name) if root.name() == Some(name.as_str()) => {}
+            ObjectPathSegment::Wildcard => {}
+            _ => return None,
+        }
+        root.find_by(&ObjectPath(tail.to_vec()))
+    }
+}
+
+impl fmt::Display for ObjectPathRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ObjectPath {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ObjectPath {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = String::deserialize(deserializer)?;
+        Ok(Self::new(&path))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ObjectPathRef {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ObjectPathRef {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ObjectPath::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Serializes the given object as its path from its root.
+///
+/// The path is the same as the one stored by [`ObjectPathRef`].
+///
+/// Use [`deserialize_from_path`] to read it back as an [`ObjectPathRef`].
+#[cfg(feature = "serde")]
+pub fn serialize_as_path<T: Kind, S: serde::Serializer>(
+    object: &impl ObjectHierarchy<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&ObjectPathRef::new(object))
+}
+
+/// Deserializes an [`ObjectPathRef`] which was serialized using [`serialize_as_path`].
+///
+/// The returned reference may be resolved using [`ObjectPathRef::resolve`] once the hierarchy is loaded.
+#[cfg(feature = "serde")]
+pub fn deserialize_from_path<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ObjectPathRef, D::Error> {
+    serde::Deserialize::deserialize(deserializer)
+}
+
+/// A single segment of an [`ObjectPath`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectPathSegment {
+    /// `.` or an empty segment, which represents the current object.
+    Self_,
+    /// `..`, which represents the parent object.
+    Parent,
+    /// `*`, which represents any child object.
+    Wildcard,
+    /// `Name`, which represents the child object with the given name.
+    Name(String),
+    /// `[n]`, which represents the child object at index `n`.
+    Index(usize),
+    /// `Name[n]`, which represents the child object at index `n` among all children with the given name.
+    NameIndex(String, usize),
+}
+
+impl ObjectPathSegment {
+    fn new(segment: &str) -> Self {
+        match segment {
+            "" | "." => Self::Self_,
+            ".." => Self::Parent,
+            "*" => Self::Wildcard,
+            _ => match parse_indexed_segment(segment) {
+                Some(("", index)) => Self::Index(index),
+                Some((name, index)) => Self::NameIndex(name.to_owned(), index),
+                None => Self::Name(segment.to_owned()),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ObjectPathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Self_ => f.write_str("."),
+            Self::Parent => f.write_str(".."),
+            Self::Wildcard => f.write_str("*"),
+            Self::Name(name) => f.write_str(name),
+            Self::Index(index) => write!(f, "[{index}]"),
+            Self::NameIndex(name, index) => write!(f, "{name}[{index}]"),
+        }
+    }
+}
+
+/// Splits the first segment from the given path, and returns it along with the rest of the path, if any.
+fn split_segment<'a>(path: &'a str, separator: &str) -> (&'a str, Option<&'a str>) {
+    for token in ["..", "."] {
+        if let Some(rest) = path.strip_prefix(token) {
+            if rest.is_empty() {
+                return (token, None);
+            }
+            if let Some(rest) = rest.strip_prefix(separator) {
+                return (token, Some(rest));
+            }
+        }
+    }
+    match path.split_once(separator) {
+        Some((segment, rest)) => (segment, Some(rest)),
+        None => (path, None),
+    }
+}
+
+/// Splits an indexed path segment, such as `[2]` or `Name[1]`, into its name and index.
+fn parse_indexed_segment(segment: &str) -> Option<(&str, usize)> {
+    let (name, index) = segment.strip_suffix(']')?.rsplit_once('[')?;
+    Some((name, index.parse().ok()?))
+}
@@ -0,0 +1,76 @@
+use bevy_app::prelude::*;
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{Children, Parent};
+use moonshine_util::hierarchy::HierarchyQuery;
+
+/// A [`Plugin`] which sends an [`ObjectHierarchyChanged`] event whenever an object hierarchy changes.
+///
+/// A hierarchy is considered changed when any [`Parent`], [`Children`], or [`Name`] component
+/// is inserted, replaced, or removed from any of its objects. These events are sent immediately by observers.
+///
+/// Some hierarchy commands, such as reparenting an object to a parent which already has children,
+/// modify these components in place instead. Such changes are detected and sent in [`Last`].
+pub struct ObjectHierarchyPlugin;
+
+impl Plugin for ObjectHierarchyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ObjectHierarchyChanged>()
+            .add_observer(hierarchy_changed::<OnInsert, Parent>)
+            .add_observer(hierarchy_changed::<OnReplace, Parent>)
+            .add_observer(parent_removed)
+            .add_observer(hierarchy_changed::<OnInsert, Children>)
+            .add_observer(hierarchy_changed::<OnRemove, Children>)
+            .add_observer(hierarchy_changed::<OnInsert, Name>)
+            .add_observer(hierarchy_changed::<OnRemove, Name>)
+            .add_systems(Last, send_hierarchy_changed);
+    }
+}
+
+/// An [`Event`] sent by the [`ObjectHierarchyPlugin`] when an object hierarchy changes.
+///
+/// This is useful for keeping derived data, such as caches of object paths, coherent with the hierarchy.
+/// Multiple events may be sent for the same hierarchy within a single frame.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObjectHierarchyChanged {
+    /// The root of the changed hierarchy.
+    ///
+    /// If an object is detached from its parent, an event is sent for both the hierarchy it was
+    /// detached from and the object itself, as the root of its own hierarchy.
+    pub root: Entity,
+}
+
+fn hierarchy_changed<E: Event, C: Component>(
+    trigger: Trigger<E, C>,
+    hierarchy: HierarchyQuery,
+    mut events: EventWriter<ObjectHierarchyChanged>,
+) {
+    let entity = trigger.entity();
+    let root = hierarchy.ancestors(entity).last().unwrap_or(entity);
+    events.send(ObjectHierarchyChanged { root });
+}
+
+fn parent_removed(
+    trigger: Trigger<OnRemove, Parent>,
+    hierarchy: HierarchyQuery,
+    mut events: EventWriter<ObjectHierarchyChanged>,
+) {
+    let entity = trigger.entity();
+    if let Some(root) = hierarchy.ancestors(entity).last() {
+        events.send(ObjectHierarchyChanged { root });
+    }
+    events.send(ObjectHierarchyChanged { root: entity });
+}
+
+type HierarchyMutated = Or<(Changed<Parent>, Changed<Children>, Changed<Name>)>;
+
+fn send_hierarchy_changed(
+    changed: Query<Entity, HierarchyMutated>,
+    hierarchy: HierarchyQuery,
+    mut events: EventWriter<ObjectHierarchyChanged>,
+) {
+    for entity in changed.iter() {
+        let root = hierarchy.ancestors(entity).last().unwrap_or(entity);
+        events.send(ObjectHierarchyChanged { root });
+    }
+}
@@ -1,7 +1,7 @@
 use bevy_ecs::prelude::*;
 use moonshine_kind::{prelude::*, Any, CastInto};
 
-use crate::{Object, ObjectHierarchy, ObjectInstance, ObjectRef};
+use crate::{Object, ObjectHierarchy, ObjectInstance, ObjectMut, ObjectRef, ObjectRefMut};
 
 pub trait ObjectRebind<T: Kind = Any>: ObjectInstance<T> + Sized {
     type Rebind<U: Kind>: ObjectHierarchy<U>;
@@ -166,6 +166,20 @@ pub trait ObjectRebind<T: Kind = Any>: ObjectInstance<T> + Sized {
         self.rebind_as(self.instance().cast_into_any())
     }
 
+    /// Casts this object into an object of [`Component`] `U`, if its [`Entity`] has it.
+    ///
+    /// # Usage
+    ///
+    /// This is useful when you have an [`Object<Any>`] and you want an [`Object<U>`],
+    /// but the conversion can only be checked at runtime.
+    ///
+    /// Returns `None` if the entity does not exist or does not have a `U` component.
+    fn try_cast_into<U: Component>(&self, world: &World) -> Option<Self::Rebind<U>> {
+        let entity = world.get_entity(self.entity()).ok()?;
+        let instance = Instance::<U>::from_entity(entity)?;
+        Some(self.rebind_as(instance))
+    }
+
     /// Casts this object into another of a different [`Kind`].
     ///
     /// # Usage
@@ -200,3 +214,19 @@ impl<'w, 's, 'a, T: Kind> ObjectRebind<T> for ObjectRef<'w, 's, 'a, T> {
         ObjectRef(self.0, self.1.rebind_as(instance))
     }
 }
+
+impl<'w, 's, 'a, T: Component> ObjectRebind<T> for ObjectMut<'w, 's, 'a, T> {
+    type Rebind<U: Kind> = Object<'w, 's, 'a, U>;
+
+    fn rebind_as<U: Kind>(&self, instance: Instance<U>) -> Self::Rebind<U> {
+        self.1.rebind_as(instance)
+    }
+}
+
+impl<'w, 's, 'a, T: Kind> ObjectRebind<T> for ObjectRefMut<'w, 's, 'a, T> {
+    type Rebind<U: Kind> = Object<'w, 's, 'a, U>;
+
+    fn rebind_as<U: Kind>(&self, instance: Instance<U>) -> Self::Rebind<U> {
+        self.1.rebind_as(instance)
+    }
+}